@@ -0,0 +1,405 @@
+//! The hexadecimal floating point arithmetic shared by every operation.
+//!
+//! Values are unpacked into a sign, characteristic, and fraction of some number of hex digits,
+//! operated on following the rules in IBM's Principles of Operation, and packed back into bits.
+//! Characteristics are kept as `i32`s so that intermediate results can leave the `0..=127` range;
+//! `finish()` decides what happens when a final result does.
+
+#[cfg(not(feature = "std"))]
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::cmp::Ordering;
+
+use crate::Exception;
+
+/// The program mask bits which decide the results of maskable exceptions.
+#[derive(Copy, Clone)]
+pub(crate) struct Masks {
+    /// When set, exponent underflow produces a result with a characteristic 128 too large and is
+    /// reported. When clear, the result is a true zero and nothing is reported.
+    pub(crate) exponent_underflow: bool,
+    /// When set, a zero sum keeps its characteristic and is reported. When clear, the result is a
+    /// true zero and nothing is reported.
+    pub(crate) significance: bool,
+}
+
+impl Masks {
+    /// Both masks clear, as used by the `core::ops` impls.
+    pub(crate) const CLEAR: Self = Self {
+        exponent_underflow: false,
+        significance: false,
+    };
+}
+
+/// An IBM float unpacked into its sign, characteristic, and a right-aligned fraction.
+#[derive(Copy, Clone)]
+pub(crate) struct Hfp {
+    pub(crate) negative: bool,
+    pub(crate) characteristic: i32,
+    pub(crate) fraction: u128,
+}
+
+impl Hfp {
+    /// A positive true zero.
+    pub(crate) const ZERO: Self = Self {
+        negative: false,
+        characteristic: 0,
+        fraction: 0,
+    };
+
+    /// Unpack a float of `digits` hex digits from the low bits of a `u128`.
+    #[inline]
+    pub(crate) const fn unpack(bits: u128, digits: u32) -> Self {
+        Self {
+            negative: (bits >> (4 * digits + 7)) & 1 != 0,
+            characteristic: ((bits >> (4 * digits)) & 0x7f) as i32,
+            fraction: bits & !(!0 << (4 * digits)),
+        }
+    }
+
+    /// Pack into a float of `digits` hex digits in the low bits of a `u128`. The characteristic
+    /// must be in range, i.e. this must be the output of `finish()`.
+    #[inline]
+    pub(crate) const fn pack(self, digits: u32) -> u128 {
+        (self.negative as u128) << (4 * digits + 7)
+            | (self.characteristic as u128) << (4 * digits)
+            | self.fraction
+    }
+
+    /// Shift out leading zero hex digits of a nonzero fraction of `digits` hex digits, adjusting
+    /// the characteristic to match.
+    #[inline]
+    pub(crate) const fn normalize(self, digits: u32) -> Self {
+        let shift = (self.fraction.leading_zeros() - (128 - 4 * digits)) / 4;
+        Self {
+            negative: self.negative,
+            characteristic: self.characteristic - shift as i32,
+            fraction: self.fraction << (4 * shift),
+        }
+    }
+
+    /// Bring the characteristic of a final result into range, applying the exponent overflow and
+    /// underflow rules.
+    ///
+    /// Overflow always produces a characteristic 128 too small and is always reported. Underflow
+    /// depends on the exponent underflow mask.
+    pub(crate) const fn finish(self, masks: Masks) -> (Self, Option<Exception>) {
+        if self.characteristic > 0x7f {
+            (self.wrap(), Some(Exception::ExponentOverflow))
+        } else if self.characteristic >= 0 {
+            (self, None)
+        } else if masks.exponent_underflow {
+            (self.wrap(), Some(Exception::ExponentUnderflow))
+        } else {
+            (Self::ZERO, None)
+        }
+    }
+
+    /// Replace the characteristic with its value modulo 128.
+    #[inline]
+    const fn wrap(self) -> Self {
+        Self {
+            negative: self.negative,
+            characteristic: self.characteristic & 0x7f,
+            fraction: self.fraction,
+        }
+    }
+}
+
+/// Add two floats of `digits` hex digits, as ADD NORMALIZED or, if `normalize` is false, ADD
+/// UNNORMALIZED.
+///
+/// The operands are aligned with one guard digit, as by `intermediate_sum()`. A carry out of the
+/// sum shifts it back right by one digit. A zero sum, including the guard digit, is a significance
+/// exception; otherwise the sum is optionally normalized, and the guard digit is truncated.
+pub(crate) const fn add(
+    a: Hfp,
+    b: Hfp,
+    digits: u32,
+    masks: Masks,
+    normalize: bool,
+) -> (Hfp, Option<Exception>) {
+    let Hfp {
+        negative,
+        characteristic,
+        fraction,
+    } = intermediate_sum(a, b, digits);
+
+    if fraction == 0 {
+        return if masks.significance {
+            (
+                Hfp {
+                    negative: false,
+                    characteristic,
+                    fraction: 0,
+                },
+                Some(Exception::Significance),
+            )
+        } else {
+            (Hfp::ZERO, None)
+        };
+    }
+
+    // Handle a carry out of the leading digit
+    let mut sum = Hfp {
+        negative,
+        characteristic,
+        fraction,
+    };
+    if fraction >> (4 * (digits + 1)) != 0 {
+        sum.fraction >>= 4;
+        sum.characteristic += 1;
+    }
+
+    if normalize {
+        sum = sum.normalize(digits + 1);
+    }
+    sum.fraction >>= 4;
+    sum.finish(masks)
+}
+
+/// Multiply two floats of `digits` hex digits, as MULTIPLY does for equal operand and result
+/// lengths.
+///
+/// The exact product is truncated to `digits` hex digits.
+pub(crate) const fn mul(a: Hfp, b: Hfp, digits: u32, masks: Masks) -> (Hfp, Option<Exception>) {
+    let product = mul_exact(a, b, digits);
+    Hfp {
+        negative: product.negative,
+        characteristic: product.characteristic,
+        fraction: product.fraction >> (4 * digits),
+    }
+    .finish(masks)
+}
+
+/// Multiply two floats of `digits` hex digits exactly, returning a normalized product of
+/// `2 * digits` hex digits whose characteristic may be out of range.
+///
+/// Both operands are normalized first, so the product needs at most one digit of normalization.
+/// A zero operand produces a true zero.
+pub(crate) const fn mul_exact(a: Hfp, b: Hfp, digits: u32) -> Hfp {
+    if a.fraction == 0 || b.fraction == 0 {
+        return Hfp::ZERO;
+    }
+
+    let (a, b) = (a.normalize(digits), b.normalize(digits));
+    let product = Hfp {
+        negative: a.negative != b.negative,
+        characteristic: a.characteristic + b.characteristic - 64,
+        fraction: a.fraction * b.fraction,
+    };
+    product.normalize(2 * digits)
+}
+
+/// Multiply two floats of `digits` hex digits and add a third, as MULTIPLY AND ADD does.
+///
+/// The product is not truncated. Instead, the addend is extended to `2 * digits` hex digits, the
+/// sum is formed at that length as for ADD NORMALIZED, and the normalized sum is then truncated to
+/// `digits` hex digits. Unlike ADD NORMALIZED, a zero sum is always a true zero, since these
+/// instructions do not recognize the significance exception.
+pub(crate) const fn mul_add(
+    a: Hfp,
+    b: Hfp,
+    c: Hfp,
+    digits: u32,
+    masks: Masks,
+) -> (Hfp, Option<Exception>) {
+    let product = mul_exact(a, b, digits);
+    let addend = Hfp {
+        negative: c.negative,
+        characteristic: c.characteristic,
+        fraction: c.fraction << (4 * digits),
+    };
+    let masks = Masks {
+        significance: false,
+        ..masks
+    };
+    let (sum, exception) = add(product, addend, 2 * digits, masks, true);
+    (
+        Hfp {
+            negative: sum.negative,
+            characteristic: sum.characteristic,
+            fraction: sum.fraction >> (4 * digits),
+        },
+        exception,
+    )
+}
+
+/// Divide two floats of `digits` hex digits, as DIVIDE does for equal operand and result
+/// lengths.
+///
+/// Both operands are normalized first, and the quotient is truncated to `digits` hex digits. A
+/// zero divisor is a floating-point divide exception, which suppresses the operation and leaves
+/// the dividend unchanged. Otherwise, a zero dividend produces a true zero.
+pub(crate) const fn div(a: Hfp, b: Hfp, digits: u32, masks: Masks) -> (Hfp, Option<Exception>) {
+    if b.fraction == 0 {
+        return (a, Some(Exception::FloatingPointDivide));
+    }
+    if a.fraction == 0 {
+        return (Hfp::ZERO, None);
+    }
+
+    // Shift the divisor left a digit if needed to keep the quotient below 1
+    let (a, b) = (a.normalize(digits), b.normalize(digits));
+    let (characteristic, divisor) = if a.fraction < b.fraction {
+        (a.characteristic - b.characteristic + 64, b.fraction)
+    } else {
+        (a.characteristic - b.characteristic + 65, b.fraction << 4)
+    };
+    Hfp {
+        negative: a.negative != b.negative,
+        characteristic,
+        fraction: (a.fraction << (4 * digits)) / divisor,
+    }
+    .finish(masks)
+}
+
+/// Take the square root of a float of `digits` hex digits, as SQUARE ROOT does.
+///
+/// The operand is normalized first, and the root is rounded to `digits` hex digits by adding one
+/// half in the last place and truncating. The result cannot overflow or underflow. A zero operand,
+/// of either sign, produces a true zero. A negative operand is a square root exception, which
+/// suppresses the operation.
+pub(crate) const fn sqrt(a: Hfp, digits: u32) -> (Hfp, Option<Exception>) {
+    if a.fraction == 0 {
+        return (Hfp::ZERO, None);
+    }
+    if a.negative {
+        return (a, Some(Exception::SquareRoot));
+    }
+
+    // Fold an odd exponent into the fraction, so that the exponent halves evenly
+    let a = a.normalize(digits);
+    let exponent = a.characteristic - 64;
+    let radicand = if exponent & 1 == 0 {
+        a.fraction << (4 * digits)
+    } else {
+        a.fraction << (4 * digits - 4)
+    };
+
+    // floor(2 * sqrt(n)), plus one, halved, is sqrt(n) rounded half up
+    Hfp {
+        negative: false,
+        characteristic: (exponent + 1).div_euclid(2) + 64,
+        fraction: (isqrt(radicand << 2) + 1) >> 1,
+    }
+    .finish(Masks::CLEAR)
+}
+
+/// Halve a float of `digits` hex digits, as HALVE does.
+///
+/// The fraction is shifted right one bit into a guard digit, and the result is normalized and
+/// truncated. A zero fraction produces a true zero.
+pub(crate) const fn halve(a: Hfp, digits: u32, masks: Masks) -> (Hfp, Option<Exception>) {
+    if a.fraction == 0 {
+        return (Hfp::ZERO, None);
+    }
+
+    let mut half = Hfp {
+        negative: a.negative,
+        characteristic: a.characteristic,
+        fraction: a.fraction << 3,
+    }
+    .normalize(digits + 1);
+    half.fraction >>= 4;
+    half.finish(masks)
+}
+
+/// Round a float of `digits + dropped` hex digits to `digits` hex digits, as LOAD ROUNDED does.
+///
+/// One is added in the leftmost bit being dropped, and the fraction is truncated. A carry out of
+/// the leading digit shifts the fraction right one digit, which can overflow the characteristic.
+/// The result is not normalized, so zeros keep their sign and characteristic.
+pub(crate) const fn round(a: Hfp, digits: u32, dropped: u32) -> (Hfp, Option<Exception>) {
+    let mut rounded = Hfp {
+        negative: a.negative,
+        characteristic: a.characteristic,
+        fraction: (a.fraction + (1 << (4 * dropped - 1))) >> (4 * dropped),
+    };
+    if rounded.fraction >> (4 * digits) != 0 {
+        rounded.fraction >>= 4;
+        rounded.characteristic += 1;
+    }
+    rounded.finish(Masks::CLEAR)
+}
+
+/// Compute `floor(sqrt(n))`, one bit at a time.
+const fn isqrt(n: u128) -> u128 {
+    let mut remainder = n;
+    let mut root = 0u128;
+    let mut bit = 1u128 << ((127 - n.leading_zeros()) & !1);
+    while bit != 0 {
+        if remainder >= root + bit {
+            remainder -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
+
+/// Compare two floats of `digits` hex digits, as COMPARE does.
+///
+/// The comparison follows the rules for SUBTRACT NORMALIZED, so values whose difference vanishes
+/// when aligned with one guard digit compare equal. No exceptions are possible.
+pub(crate) const fn compare(a: Hfp, b: Hfp, digits: u32) -> Ordering {
+    let difference = intermediate_sum(
+        a,
+        Hfp {
+            negative: !b.negative,
+            characteristic: b.characteristic,
+            fraction: b.fraction,
+        },
+        digits,
+    );
+    if difference.fraction == 0 {
+        Ordering::Equal
+    } else if difference.negative {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+/// Form the unnormalized sum of two floats of `digits` hex digits, with a guard digit and a
+/// possible carry digit.
+///
+/// The fraction with the smaller characteristic is shifted right to align the radix points,
+/// keeping one guard digit and losing everything beyond it. The sign is that of the larger
+/// magnitude.
+const fn intermediate_sum(a: Hfp, b: Hfp, digits: u32) -> Hfp {
+    // Align both fractions with a guard digit
+    let characteristic = if a.characteristic > b.characteristic {
+        a.characteristic
+    } else {
+        b.characteristic
+    };
+    let fa = align(a.fraction << 4, characteristic - a.characteristic, digits);
+    let fb = align(b.fraction << 4, characteristic - b.characteristic, digits);
+
+    // Add the magnitudes, taking the sign of the larger when they differ
+    let (negative, fraction) = if a.negative == b.negative {
+        (a.negative, fa + fb)
+    } else if fa >= fb {
+        (a.negative, fa - fb)
+    } else {
+        (b.negative, fb - fa)
+    };
+    Hfp {
+        negative,
+        characteristic,
+        fraction,
+    }
+}
+
+/// Shift a fraction of `digits` hex digits plus a guard digit right by `shift` hex digits.
+#[inline]
+const fn align(fraction: u128, shift: i32, digits: u32) -> u128 {
+    if shift as u32 > digits {
+        0
+    } else {
+        fraction >> (4 * shift)
+    }
+}
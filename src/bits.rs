@@ -0,0 +1,73 @@
+#[cfg(not(feature = "std"))]
+use core::{cmp, hash};
+#[cfg(feature = "std")]
+use std::{cmp, hash};
+
+use crate::IbmFloat;
+
+/// A wrapper which compares, orders, and hashes an IBM float by its exact bit pattern.
+///
+/// `F32` and `F64` compare numerically, so `+0` equals `-0`, and all zeros equal each other
+/// regardless of their characteristic. `Bits` instead treats two values as equal only if their
+/// encodings are identical, making it suitable for deduplication and for use as a `HashMap` or
+/// `BTreeMap` key.
+///
+/// `Bits` orders values by their encoding interpreted as an unsigned integer. This is *not*
+/// numeric order: negative values sort after all positive values, and among the negative values,
+/// larger magnitudes sort later.
+///
+/// ```
+/// use std::collections::HashSet;
+/// use ibmfloat::{Bits, F32};
+///
+/// let positive_zero = F32::from_bits(0x00000000);
+/// let negative_zero = F32::from_bits(0x80000000);
+/// let dirty_zero = F32::from_bits(0x42000000);
+/// assert!(positive_zero == negative_zero && positive_zero == dirty_zero);
+///
+/// let set: HashSet<_> = [positive_zero, negative_zero, dirty_zero, positive_zero]
+///     .iter()
+///     .cloned()
+///     .map(Bits)
+///     .collect();
+/// assert_eq!(set.len(), 3);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Bits<T>(pub T);
+
+impl<T: IbmFloat> From<T> for Bits<T> {
+    #[inline]
+    fn from(v: T) -> Self {
+        Self(v)
+    }
+}
+
+impl<T: IbmFloat> PartialEq for Bits<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl<T: IbmFloat> Eq for Bits<T> {}
+
+impl<T: IbmFloat> PartialOrd for Bits<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: IbmFloat> Ord for Bits<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.0.to_bits().cmp(&other.0.to_bits())
+    }
+}
+
+impl<T: IbmFloat> hash::Hash for Bits<T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{F32, F64};
+
+/// The order in which the bytes of an IBM floating point number are stored.
+///
+/// Variants are named after the position of each byte of the big-endian representation `ABCD`,
+/// where `A` holds the sign and characteristic. The same patterns apply to 64-bit values, treating
+/// each variant as a statement about 16-bit words and the bytes within them:
+///
+/// | Variant | 32-bit   | 64-bit     | Word order    | Bytes within words |
+/// |---------|----------|------------|---------------|--------------------|
+/// | `Abcd`  | `ABCD`   | `ABCDEFGH` | big endian    | big endian         |
+/// | `Dcba`  | `DCBA`   | `HGFEDCBA` | little endian | little endian      |
+/// | `Badc`  | `BADC`   | `BADCFEHG` | big endian    | little endian      |
+/// | `Cdab`  | `CDAB`   | `GHEFCDAB` | little endian | big endian         |
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ByteOrder {
+    /// Big endian, as stored by IBM mainframes.
+    Abcd,
+    /// Little endian, as commonly produced by byte-swapping on x86 systems.
+    Dcba,
+    /// Big-endian 16-bit words, with the bytes of each word swapped.
+    Badc,
+    /// Little-endian 16-bit words, with the bytes of each word in big-endian order, as commonly
+    /// produced by Modbus gateways.
+    Cdab,
+}
+
+macro_rules! byte_order {
+    ($N:expr, $swap:ident) => {
+        /// Exchange each adjacent pair of bytes.
+        const fn $swap(mut bytes: [u8; $N]) -> [u8; $N] {
+            let mut i = 0;
+            while i < $N {
+                let b = bytes[i];
+                bytes[i] = bytes[i + 1];
+                bytes[i + 1] = b;
+                i += 2;
+            }
+            bytes
+        }
+    };
+}
+byte_order!(4, swap_pairs4);
+byte_order!(8, swap_pairs8);
+
+impl F32 {
+    /// Create a floating point value from its representation as a byte array in the given byte
+    /// order.
+    ///
+    /// ```
+    /// use ibmfloat::{ByteOrder, F32};
+    ///
+    /// assert_eq!(F32::from_bytes([0x46, 0, 0, 1], ByteOrder::Abcd).to_bits(), 0x46000001);
+    /// assert_eq!(F32::from_bytes([1, 0, 0, 0x46], ByteOrder::Dcba).to_bits(), 0x46000001);
+    /// assert_eq!(F32::from_bytes([0, 0x46, 1, 0], ByteOrder::Badc).to_bits(), 0x46000001);
+    /// assert_eq!(F32::from_bytes([0, 1, 0x46, 0], ByteOrder::Cdab).to_bits(), 0x46000001);
+    /// ```
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 4], order: ByteOrder) -> Self {
+        match order {
+            ByteOrder::Abcd => Self::from_be_bytes(bytes),
+            ByteOrder::Dcba => Self::from_le_bytes(bytes),
+            ByteOrder::Badc => Self::from_be_bytes(swap_pairs4(bytes)),
+            ByteOrder::Cdab => Self::from_le_bytes(swap_pairs4(bytes)),
+        }
+    }
+
+    /// Return the memory representation of this floating point number as a byte array in the
+    /// given byte order.
+    ///
+    /// ```
+    /// use ibmfloat::{ByteOrder, F32};
+    ///
+    /// let foreign_float = F32::from_bits(0x46000001);
+    /// assert_eq!(foreign_float.to_bytes(ByteOrder::Abcd), [0x46, 0, 0, 1]);
+    /// assert_eq!(foreign_float.to_bytes(ByteOrder::Dcba), [1, 0, 0, 0x46]);
+    /// assert_eq!(foreign_float.to_bytes(ByteOrder::Badc), [0, 0x46, 1, 0]);
+    /// assert_eq!(foreign_float.to_bytes(ByteOrder::Cdab), [0, 1, 0x46, 0]);
+    /// ```
+    #[inline]
+    pub const fn to_bytes(self, order: ByteOrder) -> [u8; 4] {
+        match order {
+            ByteOrder::Abcd => self.to_be_bytes(),
+            ByteOrder::Dcba => self.to_le_bytes(),
+            ByteOrder::Badc => swap_pairs4(self.to_be_bytes()),
+            ByteOrder::Cdab => swap_pairs4(self.to_le_bytes()),
+        }
+    }
+}
+
+impl F64 {
+    /// Create a floating point value from its representation as a byte array in the given byte
+    /// order.
+    ///
+    /// ```
+    /// use ibmfloat::{ByteOrder, F64};
+    ///
+    /// let bytes = [0x41, 0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70];
+    /// assert_eq!(F64::from_bytes(bytes, ByteOrder::Abcd).to_bits(), 0x4110203040506070);
+    ///
+    /// let bytes = [0x70, 0x60, 0x50, 0x40, 0x30, 0x20, 0x10, 0x41];
+    /// assert_eq!(F64::from_bytes(bytes, ByteOrder::Dcba).to_bits(), 0x4110203040506070);
+    ///
+    /// let bytes = [0x10, 0x41, 0x30, 0x20, 0x50, 0x40, 0x70, 0x60];
+    /// assert_eq!(F64::from_bytes(bytes, ByteOrder::Badc).to_bits(), 0x4110203040506070);
+    ///
+    /// let bytes = [0x60, 0x70, 0x40, 0x50, 0x20, 0x30, 0x41, 0x10];
+    /// assert_eq!(F64::from_bytes(bytes, ByteOrder::Cdab).to_bits(), 0x4110203040506070);
+    /// ```
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 8], order: ByteOrder) -> Self {
+        match order {
+            ByteOrder::Abcd => Self::from_be_bytes(bytes),
+            ByteOrder::Dcba => Self::from_le_bytes(bytes),
+            ByteOrder::Badc => Self::from_be_bytes(swap_pairs8(bytes)),
+            ByteOrder::Cdab => Self::from_le_bytes(swap_pairs8(bytes)),
+        }
+    }
+
+    /// Return the memory representation of this floating point number as a byte array in the
+    /// given byte order.
+    ///
+    /// ```
+    /// use ibmfloat::{ByteOrder, F64};
+    ///
+    /// let foreign_float = F64::from_bits(0x4110203040506070);
+    /// assert_eq!(
+    ///     foreign_float.to_bytes(ByteOrder::Cdab),
+    ///     [0x60, 0x70, 0x40, 0x50, 0x20, 0x30, 0x41, 0x10]
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_bytes(self, order: ByteOrder) -> [u8; 8] {
+        match order {
+            ByteOrder::Abcd => self.to_be_bytes(),
+            ByteOrder::Dcba => self.to_le_bytes(),
+            ByteOrder::Badc => swap_pairs8(self.to_be_bytes()),
+            ByteOrder::Cdab => swap_pairs8(self.to_le_bytes()),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(not(feature = "std"))]
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::cmp::Ordering;
+
+use crate::convert::split128;
+use crate::{HexFloat, F128, F32, F64};
+
+/// An IBM float unpacked into its sign and a normalized magnitude, such that values of any width
+/// can be compared exactly.
+#[derive(Copy, Clone)]
+pub(crate) struct Normalized {
+    negative: bool,
+    /// The power of 16 by which `fraction` is scaled, i.e. the characteristic less 64, reduced by
+    /// one for each leading zero digit removed during normalization.
+    exponent: i32,
+    /// The fraction, left-aligned so that its radix point is above the most significant bit, with
+    /// a nonzero leading hex digit. Zero for all zeros, regardless of sign or characteristic.
+    fraction: u128,
+}
+
+impl Normalized {
+    /// Normalize a sign, characteristic, and left-aligned fraction.
+    pub(crate) const fn new(negative: bool, characteristic: i32, fraction: u128) -> Self {
+        if fraction == 0 {
+            return Self {
+                negative: false,
+                exponent: 0,
+                fraction: 0,
+            };
+        }
+
+        let digits = fraction.leading_zeros() / 4;
+        Self {
+            negative,
+            exponent: characteristic - 64 - digits as i32,
+            fraction: fraction << (digits * 4),
+        }
+    }
+
+    /// Exactly convert a finite IEEE-754 value, returning `None` for infinities and NaN.
+    fn from_ieee(v: f64) -> Option<Self> {
+        if !v.is_finite() {
+            return None;
+        }
+
+        // Decompose into an integer mantissa and power of two
+        let bits = v.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (bits & 0x000f_ffff_ffff_ffff, -1074)
+        } else {
+            (
+                bits & 0x000f_ffff_ffff_ffff | 1 << 52,
+                biased_exponent - 1075,
+            )
+        };
+
+        // Fold the remainder of the power of two into the mantissa to get a power of 16. The
+        // mantissa then occupies at most 56 bits, which we treat as a 14-digit fraction.
+        let mantissa = mantissa << exponent.rem_euclid(4);
+        Some(Self::new(
+            v.is_sign_negative(),
+            exponent.div_euclid(4) + 14 + 64,
+            (mantissa as u128) << 72,
+        ))
+    }
+
+    /// Compare against an IEEE-754 value exactly, returning `None` if `other` is NaN.
+    pub(crate) fn partial_cmp_ieee(&self, other: f64) -> Option<Ordering> {
+        match Self::from_ieee(other) {
+            Some(other) => Some(self.cmp(&other)),
+            None if other.is_nan() => None,
+            None if other > 0.0 => Some(Ordering::Less),
+            None => Some(Ordering::Greater),
+        }
+    }
+
+    /// Compare two values numerically. All zeros compare equal.
+    pub(crate) fn cmp(&self, other: &Self) -> Ordering {
+        match (self.fraction == 0, other.fraction == 0) {
+            (true, true) => Ordering::Equal,
+            (true, false) if other.negative => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, true) if self.negative => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => match (self.negative, other.negative) {
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (negative, _) => {
+                    let magnitude =
+                        (self.exponent, self.fraction).cmp(&(other.exponent, other.fraction));
+                    if negative {
+                        magnitude.reverse()
+                    } else {
+                        magnitude
+                    }
+                }
+            },
+        }
+    }
+}
+
+impl From<F32> for Normalized {
+    #[inline]
+    fn from(v: F32) -> Self {
+        Self::from(HexFloat::<32>::from(v))
+    }
+}
+
+impl From<F64> for Normalized {
+    #[inline]
+    fn from(v: F64) -> Self {
+        Self::from(HexFloat::<64>::from(v))
+    }
+}
+
+impl From<F128> for Normalized {
+    #[inline]
+    fn from(v: F128) -> Self {
+        let (sign, characteristic, fraction) = split128(v.to_bits());
+        Self::new(sign != 0, characteristic as i32, fraction << 16)
+    }
+}
+
+macro_rules! compare {
+    ($ibm:ty, $other:ty) => {
+        impl PartialEq<$other> for $ibm {
+            #[inline]
+            fn eq(&self, other: &$other) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl PartialOrd<$other> for $ibm {
+            #[inline]
+            fn partial_cmp(&self, other: &$other) -> Option<Ordering> {
+                Some(Normalized::from(*self).cmp(&Normalized::from(*other)))
+            }
+        }
+    };
+    ($ibm:ty, ieee $ieee:ty) => {
+        impl PartialEq<$ieee> for $ibm {
+            #[inline]
+            fn eq(&self, other: &$ieee) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl PartialOrd<$ieee> for $ibm {
+            #[inline]
+            fn partial_cmp(&self, other: &$ieee) -> Option<Ordering> {
+                Normalized::from(*self).partial_cmp_ieee(f64::from(*other))
+            }
+        }
+
+        impl PartialEq<$ibm> for $ieee {
+            #[inline]
+            fn eq(&self, other: &$ibm) -> bool {
+                other == self
+            }
+        }
+
+        impl PartialOrd<$ibm> for $ieee {
+            #[inline]
+            fn partial_cmp(&self, other: &$ibm) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+compare!(F32, F64);
+compare!(F64, F32);
+compare!(F32, ieee f32);
+compare!(F32, ieee f64);
+compare!(F64, ieee f32);
+compare!(F64, ieee f64);
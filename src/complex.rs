@@ -0,0 +1,125 @@
+//! Complex numbers made of pairs of IBM floats.
+
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+use crate::{F32, F64};
+
+/// A complex number made of two 32-bit IBM floats, as in Fortran `COMPLEX*8`.
+///
+/// The real part precedes the imaginary part in memory, so the big-endian representation is
+/// the real part's big-endian bytes followed by the imaginary part's.
+///
+/// ```
+/// use ibmfloat::C32;
+///
+/// let value = C32::from_be_bytes([0x41, 0x10, 0, 0, 0xc2, 0x76, 0xa0, 0]);
+///
+/// assert_eq!(f32::from(value.re), 1.0);
+/// assert_eq!(f32::from(value.im), -118.625);
+/// ```
+///
+/// With the `num-complex` feature, `C32` converts to `Complex<f32>` and `Complex<f64>` by
+/// converting each part.
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct C32 {
+    /// The real part.
+    pub re: F32,
+    /// The imaginary part.
+    pub im: F32,
+}
+
+/// A complex number made of two 64-bit IBM floats, as in Fortran `COMPLEX*16`.
+///
+/// The real part precedes the imaginary part in memory, so the big-endian representation is
+/// the real part's big-endian bytes followed by the imaginary part's.
+///
+/// ```
+/// use ibmfloat::C64;
+///
+/// let value = C64::from_be_bytes([
+///     0x41, 0x10, 0, 0, 0, 0, 0, 0, 0xc2, 0x76, 0xa0, 0, 0, 0, 0, 0,
+/// ]);
+///
+/// assert_eq!(f64::from(value.re), 1.0);
+/// assert_eq!(f64::from(value.im), -118.625);
+/// ```
+///
+/// With the `num-complex` feature, `C64` converts to `Complex<f32>` and `Complex<f64>` by
+/// converting each part.
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct C64 {
+    /// The real part.
+    pub re: F64,
+    /// The imaginary part.
+    pub im: F64,
+}
+
+macro_rules! complex {
+    ($t:ident, $f:ident, $N:expr, $H:expr) => {
+        impl $t {
+            /// Create a complex number from its real and imaginary parts.
+            #[inline]
+            pub const fn new(re: $f, im: $f) -> Self {
+                Self { re, im }
+            }
+
+            /// Create a complex number from its representation as a byte array in big endian.
+            #[inline]
+            pub const fn from_be_bytes(bytes: [u8; $N]) -> Self {
+                let mut re = [0u8; $H];
+                let mut im = [0u8; $H];
+                let mut i = 0;
+                while i < $H {
+                    re[i] = bytes[i];
+                    im[i] = bytes[$H + i];
+                    i += 1;
+                }
+                Self::new($f::from_be_bytes(re), $f::from_be_bytes(im))
+            }
+
+            /// Return the memory representation of this complex number as a byte array in
+            /// big-endian (network) byte order.
+            #[inline]
+            pub const fn to_be_bytes(self) -> [u8; $N] {
+                let re = self.re.to_be_bytes();
+                let im = self.im.to_be_bytes();
+                let mut bytes = [0u8; $N];
+                let mut i = 0;
+                while i < $H {
+                    bytes[i] = re[i];
+                    bytes[$H + i] = im[i];
+                    i += 1;
+                }
+                bytes
+            }
+        }
+
+        impl From<($f, $f)> for $t {
+            #[inline]
+            fn from((re, im): ($f, $f)) -> Self {
+                Self::new(re, im)
+            }
+        }
+
+        #[cfg(feature = "num-complex")]
+        impl From<$t> for Complex<f32> {
+            #[inline]
+            fn from(v: $t) -> Self {
+                Complex::new(v.re.into(), v.im.into())
+            }
+        }
+
+        #[cfg(feature = "num-complex")]
+        impl From<$t> for Complex<f64> {
+            #[inline]
+            fn from(v: $t) -> Self {
+                Complex::new(v.re.into(), v.im.into())
+            }
+        }
+    };
+}
+complex!(C32, F32, 8, 4);
+complex!(C64, F64, 16, 8);
@@ -0,0 +1,90 @@
+#[cfg(not(feature = "std"))]
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::cmp::Ordering;
+
+/// The condition code set by a hexadecimal floating point instruction.
+///
+/// Comparisons and the load-and-test family set the condition code from their result, which a
+/// program then tests with BRANCH ON CONDITION:
+///
+/// | Code  | Compare             | Load and test     |
+/// |-------|---------------------|-------------------|
+/// | `Cc0` | operands equal      | fraction zero     |
+/// | `Cc1` | first operand low   | result negative   |
+/// | `Cc2` | first operand high  | result positive   |
+/// | `Cc3` | —                   | —                 |
+///
+/// Hexadecimal floating point has no unordered values, so `Cc3` is never set by these
+/// instructions; it exists so that every code a branch mask can test is representable.
+///
+/// ```
+/// use ibmfloat::{ConditionCode, F32};
+///
+/// let cc = F32::from_bits(0x41100000).compare(F32::from_bits(0x41200000));
+/// assert_eq!(cc, ConditionCode::Cc1);
+/// assert_eq!(u8::from(cc), 1);
+///
+/// // BL (branch on low) uses mask 4
+/// assert!(cc.matches(0b0100));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ConditionCode {
+    /// Condition code 0.
+    Cc0 = 0,
+    /// Condition code 1.
+    Cc1 = 1,
+    /// Condition code 2.
+    Cc2 = 2,
+    /// Condition code 3.
+    Cc3 = 3,
+}
+
+impl ConditionCode {
+    /// Returns `true` if a branch with the 4-bit `mask` would be taken on this condition code.
+    ///
+    /// The mask's bits select condition codes 0 through 3 from most to least significant, as in
+    /// BRANCH ON CONDITION, so `0b1000` tests for `Cc0` and `0b0001` tests for `Cc3`.
+    ///
+    /// ```
+    /// use ibmfloat::ConditionCode;
+    ///
+    /// assert!(ConditionCode::Cc0.matches(0b1000));
+    /// assert!(!ConditionCode::Cc0.matches(0b0111));
+    /// assert!(ConditionCode::Cc3.matches(0b1111));
+    /// ```
+    #[inline]
+    pub const fn matches(self, mask: u8) -> bool {
+        mask & (0b1000 >> self as u8) != 0
+    }
+
+    /// The condition code COMPARE sets when the first operand is `ordering` the second.
+    #[inline]
+    pub(crate) const fn from_ordering(ordering: Ordering) -> Self {
+        match ordering {
+            Ordering::Equal => ConditionCode::Cc0,
+            Ordering::Less => ConditionCode::Cc1,
+            Ordering::Greater => ConditionCode::Cc2,
+        }
+    }
+
+    /// The condition code set by a load-and-test of a value with the given sign and fraction.
+    #[inline]
+    pub(crate) const fn from_sign(negative: bool, fraction: u128) -> Self {
+        if fraction == 0 {
+            ConditionCode::Cc0
+        } else if negative {
+            ConditionCode::Cc1
+        } else {
+            ConditionCode::Cc2
+        }
+    }
+}
+
+impl From<ConditionCode> for u8 {
+    #[inline]
+    fn from(cc: ConditionCode) -> Self {
+        cc as u8
+    }
+}
@@ -0,0 +1,375 @@
+use crate::arithmetic::{self, Hfp, Masks};
+use crate::{Exception, IbmFloat, F128, F32, F64};
+
+mod private {
+    /// Access to the raw representation, kept out of the public API.
+    pub trait Sealed: Copy {
+        /// The number of hex digits in the fraction.
+        const DIGITS: u32;
+        /// The bits of this value, zero-extended.
+        fn raw(self) -> u128;
+        /// A value with the given bits, which must fit.
+        fn from_raw(raw: u128) -> Self;
+    }
+
+    /// Rounding to a shorter format, kept out of the public API.
+    pub trait Round: Copy {
+        /// The shorter format.
+        type Rounded;
+        /// Round as LOAD ROUNDED does, returning any exception.
+        fn round(self) -> (Self::Rounded, Option<crate::Exception>);
+    }
+
+    /// Multiplication into a longer format, kept out of the public API.
+    pub trait Widen: Copy {
+        /// The longer format.
+        type Wide;
+        /// Multiply as MULTIPLY does into the longer format, under the given exponent underflow
+        /// mask, returning any exception.
+        fn mul_wide_masked(
+            self,
+            rhs: Self,
+            exponent_underflow_mask: bool,
+        ) -> (Self::Wide, Option<crate::Exception>);
+    }
+}
+
+/// An IBM floating point format which supports arithmetic through an
+/// [`HfpContext`](struct.HfpContext.html).
+///
+/// This trait is implemented for [`F32`](struct.F32.html) and [`F64`](struct.F64.html), and
+/// cannot be implemented outside this crate.
+pub trait HfpArithmetic: IbmFloat + private::Sealed {}
+
+macro_rules! sealed {
+    ($t:ident, $digits:expr) => {
+        impl private::Sealed for $t {
+            const DIGITS: u32 = $digits;
+
+            #[inline]
+            fn raw(self) -> u128 {
+                self.to_bits().into()
+            }
+
+            #[inline]
+            fn from_raw(raw: u128) -> Self {
+                Self::from_bits(raw as _)
+            }
+        }
+
+        impl HfpArithmetic for $t {}
+    };
+}
+sealed!(F32, 6);
+sealed!(F64, 14);
+
+/// An IBM floating point format which can be rounded to the next shorter format through an
+/// [`HfpContext`](struct.HfpContext.html).
+///
+/// This trait is implemented for [`F64`](struct.F64.html), which rounds to
+/// [`F32`](struct.F32.html), and [`F128`](struct.F128.html), which rounds to `F64`. It cannot be
+/// implemented outside this crate.
+pub trait HfpRounding: IbmFloat + private::Round {}
+
+macro_rules! rounding {
+    ($t:ident, $rounded:ident) => {
+        impl private::Round for $t {
+            type Rounded = $rounded;
+
+            #[inline]
+            fn round(self) -> ($rounded, Option<Exception>) {
+                $t::round(self)
+            }
+        }
+
+        impl HfpRounding for $t {}
+    };
+}
+rounding!(F64, F32);
+rounding!(F128, F64);
+
+/// An IBM floating point format whose products can be formed exactly in the next longer format
+/// through an [`HfpContext`](struct.HfpContext.html).
+///
+/// This trait is implemented for [`F32`](struct.F32.html), which widens to
+/// [`F64`](struct.F64.html), and `F64`, which widens to [`F128`](struct.F128.html). It cannot be
+/// implemented outside this crate.
+pub trait HfpWidening: IbmFloat + private::Widen {}
+
+macro_rules! widening {
+    ($t:ident, $wide:ident) => {
+        impl private::Widen for $t {
+            type Wide = $wide;
+
+            #[inline]
+            fn mul_wide_masked(
+                self,
+                rhs: Self,
+                exponent_underflow_mask: bool,
+            ) -> ($wide, Option<Exception>) {
+                let masks = Masks {
+                    exponent_underflow: exponent_underflow_mask,
+                    significance: false,
+                };
+                $t::mul_wide_masked(self, rhs, masks)
+            }
+        }
+
+        impl HfpWidening for $t {}
+    };
+}
+widening!(F32, F64);
+widening!(F64, F128);
+
+/// A program mask and a set of sticky exception flags, through which arithmetic can be performed
+/// as it would be by a program running with that mask.
+///
+/// The program mask in the PSW decides how two of the hexadecimal floating point exceptions are
+/// handled:
+///
+/// * With the exponent underflow mask set, an underflowing result keeps its sign and fraction,
+///   with a characteristic 128 too large, and the exception is reported. With it clear, the result
+///   is a true zero, and nothing is reported.
+/// * With the significance mask set, a sum or difference whose fraction is zero keeps its
+///   characteristic, and the exception is reported. With it clear, the result is a true zero, and
+///   nothing is reported. Multiply-and-add and multiply-and-subtract never report it, and always
+///   produce a true zero.
+///
+/// Exponent overflow always produces a characteristic 128 too small, and is always reported.
+/// Floating-point divide and square root exceptions are always reported, and suppress the
+/// operation, so those operations return a `Result`.
+///
+/// Reported exceptions correspond to the program interruptions the hardware would take. Each is
+/// recorded in a sticky flag, which stays raised until cleared.
+///
+/// The `core::ops` impls on [`F32`](struct.F32.html) and [`F64`](struct.F64.html) behave like a
+/// context with both masks clear, as do their inherent methods like `mul_wide()` and `halve()`.
+///
+/// ```
+/// use ibmfloat::{Exception, F32, HfpContext};
+///
+/// let tiny = F32::from_bits(0x00110000);
+/// let tinier = F32::from_bits(0x00100000);
+///
+/// let mut context = HfpContext::new();
+/// assert_eq!(context.sub(tiny, tinier).to_bits(), 0x00000000);
+/// assert!(!context.raised(Exception::ExponentUnderflow));
+///
+/// let mut context = HfpContext::new().with_exponent_underflow_mask(true);
+/// assert_eq!(context.sub(tiny, tinier).to_bits(), 0x7f100000);
+/// assert!(context.raised(Exception::ExponentUnderflow));
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct HfpContext {
+    exponent_underflow_mask: bool,
+    significance_mask: bool,
+    flags: u8,
+}
+
+impl HfpContext {
+    /// Create a context with both masks clear and no exceptions raised.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            exponent_underflow_mask: false,
+            significance_mask: false,
+            flags: 0,
+        }
+    }
+
+    /// Return this context with the exponent underflow mask set or cleared.
+    #[inline]
+    pub const fn with_exponent_underflow_mask(self, mask: bool) -> Self {
+        Self {
+            exponent_underflow_mask: mask,
+            ..self
+        }
+    }
+
+    /// Return this context with the significance mask set or cleared.
+    #[inline]
+    pub const fn with_significance_mask(self, mask: bool) -> Self {
+        Self {
+            significance_mask: mask,
+            ..self
+        }
+    }
+
+    /// Returns `true` if the exponent underflow mask is set.
+    #[inline]
+    pub const fn exponent_underflow_mask(&self) -> bool {
+        self.exponent_underflow_mask
+    }
+
+    /// Returns `true` if the significance mask is set.
+    #[inline]
+    pub const fn significance_mask(&self) -> bool {
+        self.significance_mask
+    }
+
+    /// Returns `true` if `exception` has been reported since the flags were last cleared.
+    #[inline]
+    pub const fn raised(&self, exception: Exception) -> bool {
+        self.flags & flag(exception) != 0
+    }
+
+    /// Clear all exception flags, leaving the masks unchanged.
+    #[inline]
+    pub fn clear_flags(&mut self) {
+        self.flags = 0;
+    }
+
+    /// Add two values as ADD NORMALIZED does.
+    pub fn add<T: HfpArithmetic>(&mut self, a: T, b: T) -> T {
+        let (sum, exception) = arithmetic::add(unpack(a), unpack(b), T::DIGITS, self.masks(), true);
+        self.report(exception);
+        pack(sum)
+    }
+
+    /// Subtract two values as SUBTRACT NORMALIZED does.
+    pub fn sub<T: HfpArithmetic>(&mut self, a: T, b: T) -> T {
+        let (difference, exception) =
+            arithmetic::add(unpack(a), negate(unpack(b)), T::DIGITS, self.masks(), true);
+        self.report(exception);
+        pack(difference)
+    }
+
+    /// Add two values as ADD UNNORMALIZED does.
+    pub fn add_unnormalized<T: HfpArithmetic>(&mut self, a: T, b: T) -> T {
+        let (sum, exception) =
+            arithmetic::add(unpack(a), unpack(b), T::DIGITS, self.masks(), false);
+        self.report(exception);
+        pack(sum)
+    }
+
+    /// Subtract two values as SUBTRACT UNNORMALIZED does.
+    pub fn sub_unnormalized<T: HfpArithmetic>(&mut self, a: T, b: T) -> T {
+        let (difference, exception) =
+            arithmetic::add(unpack(a), negate(unpack(b)), T::DIGITS, self.masks(), false);
+        self.report(exception);
+        pack(difference)
+    }
+
+    /// Multiply two values as MULTIPLY does.
+    pub fn mul<T: HfpArithmetic>(&mut self, a: T, b: T) -> T {
+        let (product, exception) = arithmetic::mul(unpack(a), unpack(b), T::DIGITS, self.masks());
+        self.report(exception);
+        pack(product)
+    }
+
+    /// Compute `(a * b) + c` as MULTIPLY AND ADD does.
+    ///
+    /// A zero sum is a true zero regardless of the significance mask.
+    pub fn mul_add<T: HfpArithmetic>(&mut self, a: T, b: T, c: T) -> T {
+        let (sum, exception) =
+            arithmetic::mul_add(unpack(a), unpack(b), unpack(c), T::DIGITS, self.masks());
+        self.report(exception);
+        pack(sum)
+    }
+
+    /// Compute `(a * b) - c` as MULTIPLY AND SUBTRACT does.
+    ///
+    /// A zero difference is a true zero regardless of the significance mask.
+    pub fn mul_sub<T: HfpArithmetic>(&mut self, a: T, b: T, c: T) -> T {
+        let (difference, exception) = arithmetic::mul_add(
+            unpack(a),
+            unpack(b),
+            negate(unpack(c)),
+            T::DIGITS,
+            self.masks(),
+        );
+        self.report(exception);
+        pack(difference)
+    }
+
+    /// Multiply two values without truncating the product, as MULTIPLY (short to long) and
+    /// MULTIPLY (long to extended) do.
+    pub fn mul_wide<T: HfpWidening>(&mut self, a: T, b: T) -> T::Wide {
+        let (product, exception) = a.mul_wide_masked(b, self.exponent_underflow_mask);
+        self.report(exception);
+        product
+    }
+
+    /// Halve a value as HALVE does.
+    pub fn halve<T: HfpArithmetic>(&mut self, a: T) -> T {
+        let (half, exception) = arithmetic::halve(unpack(a), T::DIGITS, self.masks());
+        self.report(exception);
+        pack(half)
+    }
+
+    /// Round a value to the next shorter format as LOAD ROUNDED does.
+    ///
+    /// A carry out of the fraction which overflows the characteristic is reported, and the result
+    /// has a characteristic 128 too small.
+    pub fn load_rounded<T: HfpRounding>(&mut self, a: T) -> T::Rounded {
+        let (rounded, exception) = a.round();
+        self.report(exception);
+        rounded
+    }
+
+    /// Divide two values as DIVIDE does, returning `Err(Exception::FloatingPointDivide)` if the
+    /// divisor is zero.
+    pub fn div<T: HfpArithmetic>(&mut self, a: T, b: T) -> Result<T, Exception> {
+        let (quotient, exception) = arithmetic::div(unpack(a), unpack(b), T::DIGITS, self.masks());
+        self.report(exception);
+        match exception {
+            Some(Exception::FloatingPointDivide) => Err(Exception::FloatingPointDivide),
+            _ => Ok(pack(quotient)),
+        }
+    }
+
+    /// Take the square root of a value as SQUARE ROOT does, returning
+    /// `Err(Exception::SquareRoot)` if it is negative.
+    pub fn sqrt<T: HfpArithmetic>(&mut self, a: T) -> Result<T, Exception> {
+        let (root, exception) = arithmetic::sqrt(unpack(a), T::DIGITS);
+        self.report(exception);
+        match exception {
+            Some(e) => Err(e),
+            None => Ok(pack(root)),
+        }
+    }
+
+    #[inline]
+    fn masks(&self) -> Masks {
+        Masks {
+            exponent_underflow: self.exponent_underflow_mask,
+            significance: self.significance_mask,
+        }
+    }
+
+    #[inline]
+    fn report(&mut self, exception: Option<Exception>) {
+        if let Some(exception) = exception {
+            self.flags |= flag(exception);
+        }
+    }
+}
+
+/// The bit recording `exception` in `HfpContext::flags`.
+const fn flag(exception: Exception) -> u8 {
+    match exception {
+        Exception::ExponentOverflow => 1 << 0,
+        Exception::ExponentUnderflow => 1 << 1,
+        Exception::Significance => 1 << 2,
+        Exception::FloatingPointDivide => 1 << 3,
+        Exception::SquareRoot => 1 << 4,
+    }
+}
+
+#[inline]
+fn unpack<T: HfpArithmetic>(value: T) -> Hfp {
+    Hfp::unpack(value.raw(), T::DIGITS)
+}
+
+#[inline]
+fn pack<T: HfpArithmetic>(value: Hfp) -> T {
+    T::from_raw(value.pack(T::DIGITS))
+}
+
+#[inline]
+const fn negate(value: Hfp) -> Hfp {
+    Hfp {
+        negative: !value.negative,
+        ..value
+    }
+}
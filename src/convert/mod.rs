@@ -1,31 +1,48 @@
-#[cfg(not(feature = "std"))]
-use core::mem::size_of;
-#[cfg(feature = "std")]
-use std::mem::size_of;
-
-macro_rules! split {
-    ($U:ty, $name:ident) => {
-        /// Split an IBM float represented at uint U into its (sign, exponent, fraction).
-        /// Sign and fraction are left in place, while exponent is slid all the way right.
-        #[inline]
-        const fn $name(ibm: $U) -> ($U, $U, $U) {
-            // Sign bit is always the top bit
-            let sign_bit_mask = <$U>::MAX & !(<$U>::MAX >> 1);
-            // Fraction is always all but the top byte
-            let fraction_mask = <$U>::MAX >> 8;
-            // Exponent is always the 7 bits in the middle
-            let exponent_mask = !sign_bit_mask & !fraction_mask;
-            let exponent_shift = (size_of::<$U>() * 8 - 8) as u8;
-            (
-                ibm & sign_bit_mask,
-                (ibm & exponent_mask) >> exponent_shift,
-                ibm & fraction_mask,
-            )
-        }
-    };
+/// Split an IBM float of `BITS` total bits, right-aligned in a u64, into its (sign, exponent,
+/// fraction). Sign and fraction are left in place, while exponent is slid all the way right.
+#[inline]
+pub(crate) const fn split_hex<const BITS: u32>(ibm: u64) -> (u64, u64, u64) {
+    // Sign bit is the top bit of the format
+    let sign_bit_mask = 1 << (BITS - 1);
+    // Fraction is all but the top byte of the format
+    let fraction_mask = u64::MAX >> (72 - BITS);
+    // Exponent is the 7 bits in between
+    let exponent_shift = BITS - 8;
+    (
+        ibm & sign_bit_mask,
+        (ibm >> exponent_shift) & 0x7f,
+        ibm & fraction_mask,
+    )
+}
+
+/// Split a native-endian IBM 32-bit float into its (sign, exponent, fraction), as
+/// `split_hex::<32>()` does.
+#[inline]
+pub(crate) const fn split32(ibm: u32) -> (u32, u32, u32) {
+    let (sign, exponent, fraction) = split_hex::<32>(ibm as u64);
+    (sign as u32, exponent as u32, fraction as u32)
+}
+
+/// Split a native-endian IBM 64-bit float into its (sign, exponent, fraction), as
+/// `split_hex::<64>()` does.
+#[inline]
+pub(crate) const fn split64(ibm: u64) -> (u64, u64, u64) {
+    split_hex::<64>(ibm)
+}
+
+/// Widen an IBM float of at most 32 total bits, right-aligned in a u64, into an IBM 32-bit float
+/// of exactly the same value by appending zero hex digits to its fraction.
+#[inline]
+pub(crate) const fn ibmhex_ibm32<const BITS: u32>(ibm: u64) -> u32 {
+    (ibm as u32) << (32 - BITS)
+}
+
+/// Widen an IBM float of `BITS` total bits, right-aligned in a u64, into an IBM 64-bit float of
+/// exactly the same value by appending zero hex digits to its fraction.
+#[inline]
+pub(crate) const fn ibmhex_ibm64<const BITS: u32>(ibm: u64) -> u64 {
+    ibm << (64 - BITS)
 }
-split!(u32, split32);
-split!(u64, split64);
 
 /// Convert a native-endian IBM 32-bit float to a native-endian IEEE-754 32-bit float.
 pub const fn ibm32ieee32(ibm: u32) -> u32 {
@@ -208,5 +225,101 @@ pub const fn ibm64ieee64(ibm: u64) -> u64 {
         .wrapping_add(ieee_fraction)
 }
 
+/// Split an IBM extended-precision float into its (sign, exponent, fraction), taking the sign and
+/// exponent from the high-order part. The sign and exponent of the low-order part are ignored, as
+/// they are by the hardware, and the two 56-bit fractions are joined into one 112-bit fraction.
+/// Sign is left in place, exponent is slid all the way right, and fraction is right-aligned.
+#[inline]
+pub(crate) const fn split128(ibm: u128) -> (u128, u128, u128) {
+    let (sign, exponent, high_fraction) = split64((ibm >> 64) as u64);
+    let (_, _, low_fraction) = split64(ibm as u64);
+    (
+        (sign as u128) << 64,
+        exponent as u128,
+        (high_fraction as u128) << 56 | low_fraction as u128,
+    )
+}
+
+// IBM extended-precision bit pattern to IEEE single-precision bit pattern.
+pub const fn ibm128ieee32(ibm: u128) -> u32 {
+    // Overflow and underflow possible; rounding can occur in both normal and subnormal cases.
+    let (sign, ibm_exponent, ibm_fraction) = split128(ibm);
+
+    // Trim the sign
+    let sign = (sign >> 96) as u32;
+
+    // Quick return for zeros.
+    if ibm_fraction == 0 {
+        return sign;
+    }
+
+    // Normalise significand so its leading bit is bit 111, adjusting exponent to match
+    let (ibm_exponent, ibm_fraction) = {
+        let shift = ibm_fraction.leading_zeros() as i32 - 16;
+        ((ibm_exponent << 2) as i32 - shift, ibm_fraction << shift)
+    };
+
+    let ieee_exponent = ibm_exponent - 131;
+    if ieee_exponent >= 254 {
+        // Overflow
+        sign.wrapping_add(0x7f80_0000)
+    } else if ieee_exponent >= 0 {
+        // Normal case; shift right 88, with round-ties-to-even
+        let round_up = if ibm_fraction & 0x017f_ffff_ffff_ffff_ffff_ffff > 0 {
+            1
+        } else {
+            0
+        };
+        let ieee_frac = ((ibm_fraction >> 87).wrapping_add(round_up) >> 1) as u32;
+        sign.wrapping_add((ieee_exponent as u32) << 23)
+            .wrapping_add(ieee_frac)
+    } else if ieee_exponent >= -32 {
+        // Possible subnormal; shift right with round-ties-to-even
+        let mask: u128 = !(!2u128 << (87 - ieee_exponent) as u128);
+        let round_up: u32 = if ibm_fraction & mask > 0 { 1 } else { 0 };
+        let ieee_frac = ((ibm_fraction >> (87 - ieee_exponent) as u128)
+            .wrapping_add(round_up as u128)
+            >> 1) as u32;
+        sign.wrapping_add(ieee_frac)
+    } else {
+        // Underflow to zero
+        sign
+    }
+}
+
+// IBM extended-precision bit pattern to IEEE double-precision bit pattern.
+pub const fn ibm128ieee64(ibm: u128) -> u64 {
+    // No overflow or underflow possible, but the extended fraction is much longer than the IEEE
+    // significand, so we'll nearly always need to round.
+    let (sign, ibm_exponent, ibm_fraction) = split128(ibm);
+
+    // Trim the sign
+    let sign = (sign >> 64) as u64;
+
+    // Quick return for zeros.
+    if ibm_fraction == 0 {
+        return sign;
+    }
+
+    // Normalise significand so its leading bit is bit 111, adjusting exponent to match
+    let (ibm_exponent, ibm_fraction) = {
+        let shift = ibm_fraction.leading_zeros() as i32 - 16;
+        ((ibm_exponent << 2) as i32 - shift, ibm_fraction << shift)
+    };
+
+    let ieee_exponent = ibm_exponent + 765;
+
+    // Right-shift by 59 bits (the difference between the normalized extended fraction and IEEE
+    // significand lengths), rounding with round-ties-to-even.
+    let round_up = if ibm_fraction & 0x0bff_ffff_ffff_ffff > 0 {
+        1
+    } else {
+        0
+    };
+    let ieee_fraction = ((ibm_fraction >> 58).wrapping_add(round_up) >> 1) as u64;
+    sign.wrapping_add((ieee_exponent as u64) << 52)
+        .wrapping_add(ieee_fraction)
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,395 @@
+use super::*;
+
+struct Testcase(u128, u32);
+impl Testcase {
+    fn verify(&self) {
+        let actual = ibm128ieee32(self.0);
+        assert_eq!(
+            actual, self.1,
+            "ibm128ieee32(0x{:032x}): got 0x{:08x}, expected 0x{:08x}",
+            self.0, actual, self.1
+        );
+    }
+}
+
+#[test]
+fn overflows() {
+    for testcase in &[
+        Testcase(0x60ffffff800000005200000000000000, 0x7f800000),
+        Testcase(0x60ffffff7fffffff52ffffffffffffff, 0x7f7fffff),
+        Testcase(0xe0ffffff80000000d200000000000001, 0xff800000),
+        Testcase(0x7fffffffffffffff71ffffffffffffff, 0x7f800000),
+        Testcase(0x61100000000000005300000000000000, 0x7f800000),
+    ] {
+        testcase.verify();
+    }
+}
+
+#[test]
+fn normal_round_ties_to_even() {
+    for testcase in &[
+        Testcase(0x41100000800000003300000000000000, 0x3f800004),
+        Testcase(0x41100000800000003300000000000001, 0x3f800004),
+        Testcase(0x41100000800000003380000000000000, 0x3f800004),
+        Testcase(0x41100001800000003300000000000000, 0x3f80000c),
+        Testcase(0x41100001800000003300000000000001, 0x3f80000c),
+        Testcase(0x41100001800000003380000000000000, 0x3f80000c),
+        Testcase(0xc110000080000000b300000000000000, 0xbf800004),
+        Testcase(0xc110000080000000b300000000000001, 0xbf800004),
+        Testcase(0xc110000080000000b380000000000000, 0xbf800004),
+        Testcase(0x41800000080000003300000000000000, 0x41000000),
+        Testcase(0x41800000080000003300000000000001, 0x41000000),
+        Testcase(0x41800000080000003380000000000000, 0x41000000),
+        Testcase(0x41800001080000003300000000000000, 0x41000001),
+        Testcase(0x41800001080000003300000000000001, 0x41000001),
+        Testcase(0x41800001080000003380000000000000, 0x41000001),
+        Testcase(0x46ffffff800000003800000000000000, 0x4b800000),
+        Testcase(0x46ffffff800000003800000000000001, 0x4b800000),
+        Testcase(0x46ffffff800000003880000000000000, 0x4b800000),
+    ] {
+        testcase.verify();
+    }
+}
+
+#[test]
+fn possible_subnormal_round_ties_to_even() {
+    for testcase in &[
+        Testcase(0x1da7c000000000000f00000000000000, 0x00000150),
+        Testcase(0x1da7c000000000000f00000000000001, 0x00000150),
+        Testcase(0x1da7c000000000000fffffffffffffff, 0x00000150),
+        Testcase(0x1da84000000000000f00000000000000, 0x00000150),
+        Testcase(0x1da84000000000000f00000000000001, 0x00000151),
+        Testcase(0x1da84000000000000fffffffffffffff, 0x00000151),
+        Testcase(0x1da8c000000000000f00000000000000, 0x00000152),
+        Testcase(0x1da8c000000000000f00000000000001, 0x00000152),
+        Testcase(0x1da8c000000000000fffffffffffffff, 0x00000152),
+        Testcase(0x1fffffffffffffff1100000000000000, 0x00020000),
+        Testcase(0x1fffffffffffffff1100000000000001, 0x00020000),
+        Testcase(0x1fffffffffffffff11ffffffffffffff, 0x00020000),
+        Testcase(0x20fffff8000000001200000000000000, 0x001fffff),
+        Testcase(0x20fffff8000000001200000000000001, 0x001fffff),
+        Testcase(0x20fffff80000000012ffffffffffffff, 0x001fffff),
+        Testcase(0x1b400000000000000d00000000000000, 0x00000000),
+        Testcase(0x1b400000000000000d00000000000001, 0x00000001),
+        Testcase(0x1b400000000000000dffffffffffffff, 0x00000001),
+        Testcase(0x9b400000000000008d00000000000000, 0x80000000),
+        Testcase(0x9b400000000000008d00000000000001, 0x80000001),
+        Testcase(0x9b400000000000008dffffffffffffff, 0x80000001),
+    ] {
+        testcase.verify();
+    }
+}
+
+#[test]
+fn underflows() {
+    for testcase in &[
+        Testcase(0x1b100000000000000d00000000000000, 0x00000000),
+        Testcase(0x1b100000000000000d00000000000001, 0x00000000),
+        Testcase(0x9b0fffffffffffff8dffffffffffffff, 0x80000000),
+        Testcase(0x000000000000000172ffffffffffffff, 0x00000000),
+        Testcase(0x00000000000000007200000000000001, 0x00000000),
+    ] {
+        testcase.verify();
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn random_test_cases() {
+    for testcase in vec![
+        Testcase(0x99b2527c41daffb98b55fdf27ac5e311, 0x80000000),
+        Testcase(0x2b228405f10a599e1da61aa0e6c414be, 0x140a1018),
+        Testcase(0xe1c7b3c978b736e3d31424a509b5a493, 0xff800000),
+        Testcase(0x5a23b884bf3c26e04c8657d00bbc9d3d, 0x720ee213),
+        Testcase(0xb740f87996a13b9aa9eb2fcfb8ea2c98, 0xac81f0f3),
+        Testcase(0x5e3e43b3ce7b942b507d7c6c3ab40d11, 0x7a790ecf),
+        Testcase(0xbccb81695b9dcb25ae65c80abc225bc3, 0xb74b8169),
+        Testcase(0x56662e92a6c6d8264882a58f9bbd1c09, 0x6acc5d25),
+        Testcase(0xd9902cba100a3254cbb7fc78ca54eae6, 0xf1102cba),
+        Testcase(0xd8535ae63956d17acade81507f391d34, 0xeea6b5cc),
+        Testcase(0xc5bc74cfa74ee2f1b71decfd8d5ff4bd, 0xc93c74d0),
+        Testcase(0xce2b1b2e11bfb615c0f3f7a07f13bf60, 0xda2c6cb8),
+        Testcase(0x4b4a04d65cb0ef1b3d758e2f24e29bb1, 0x549409ad),
+        Testcase(0xb739b944b0226099a96f79a9d91305f9, 0xac66e513),
+        Testcase(0xd0bf70b2b30c3329c24aca4524359620, 0xdf3f70b3),
+        Testcase(0x48b8ba9e801bd1fc3af932400fd3bfd8, 0x4f38ba9f),
+        Testcase(0x26dd383116de6bc418e2b1174eeedd8a, 0x0b5d3831),
+        Testcase(0x9e94b8750a2697e0903caaa7466aa790, 0x80001297),
+        Testcase(0x9d7f942a9a5cfcb68fcbd856d71f3854, 0x800000ff),
+        Testcase(0xb858cba36004a2e1aaa2bcd90fa5367a, 0xaeb19747),
+        Testcase(0xabca4c3bb8d979439d153d70a346566d, 0x954a4c3c),
+        Testcase(0x2c50a1f7cd8cc8091e3362fc76de56d4, 0x16a143f0),
+        Testcase(0x525a056dedc0488344b9dd919f30ddc2, 0x62b40adc),
+        Testcase(0xb8a8331dc3e528f8aac9e2523312409f, 0xaf28331e),
+        Testcase(0xd5f3866d6bc153b1c7c30145e56ae989, 0xe973866d),
+        Testcase(0x5a5b2da8abd58d2f4ca68b381084a82f, 0x72b65b51),
+        Testcase(0xaa64195dc60474ea9c87a7fd8ba10d07, 0x92c832bc),
+        Testcase(0xd6fbd0c2cd3bf9e7c8cbffa8c387c7bc, 0xeb7bd0c3),
+        Testcase(0xdb2b15cd1dc7aeeccda4350e961500c9, 0xf42c5734),
+        Testcase(0x2eb99b1881af0dc420cb39ff2c49eec8, 0x1b399b19),
+        Testcase(0x993b6a58ea3b88fc8b79cd49344fccf3, 0x80000000),
+        Testcase(0xa12751164950b28c93754d87f9fa44f4, 0x804ea22d),
+        Testcase(0x2ea439aa005b32b520775eb52b3ec9d0, 0x1b2439aa),
+        Testcase(0xda7313ec5335da50cc2845ea4d004c50, 0xf2e627d9),
+        Testcase(0xa799f786be8b74a19920fc3e85ed29fb, 0x8d19f787),
+        Testcase(0xc990987d7f013049bbca3d7cac377798, 0xd110987d),
+        Testcase(0xab60ebb860b7e0cd9d1d096a2f6f1447, 0x94c1d771),
+        Testcase(0xb6f79fa73d599466a8bb3d5c0d7628e2, 0xab779fa7),
+        Testcase(0xa0fb810b4db892a39255f960b7b32f3d, 0x801f7021),
+        Testcase(0xe0ca3d291e8e5c3cd238bd55e4042d4c, 0xff4a3d29),
+        Testcase(0x3c5101ef383d9abd2e99619756163999, 0x36a203de),
+        Testcase(0x40845151280a533532d78734f8631ff6, 0x3f045151),
+        Testcase(0xad162a2277f902119f3e90e3127d86dc, 0x97b15114),
+        Testcase(0x9e6dbc0c13c8116f90493c97d7e971a8, 0x80000db8),
+        Testcase(0x580bab36d16564ae4a9cb478c0b556c6, 0x6d3ab36d),
+        Testcase(0x3c9173519103ebb22e76e30ca9e5e87f, 0x37117352),
+        Testcase(0x4cfe31ff8710d9a83e575086bedef5dd, 0x577e3200),
+        Testcase(0xc4be5e5dbe4de480b6721d54f87f628f, 0xc73e5e5e),
+        Testcase(0x396b2909448e90252b1dcaccf9442c22, 0x30d65213),
+        Testcase(0x2dd12834b9ebd6de1f7798383c4dc590, 0x19512835),
+        Testcase(0xd1741e4d51216656c32ec4844b8cf25c, 0xe0e83c9b),
+        Testcase(0x270303fcd6ee7ea11997aee1fdf131b7, 0x0a40ff36),
+        Testcase(0xbfac0d14c8d43093b151e3bd06035ae8, 0xbd2c0d15),
+        Testcase(0x49b8f669edca93983b6ece625f432c01, 0x5138f66a),
+        Testcase(0x99d5e32d2abd5f088b608515993ea31d, 0x80000000),
+        Testcase(0x1fe1729372c1d86411a22657d2e3554d, 0x0001c2e5),
+        Testcase(0xcdadc4b9394c0f71bf8d454d85c753d2, 0xd92dc4b9),
+        Testcase(0xc91593cf5bf2cd3ebb0d281067a2a31b, 0xcfac9e7b),
+        Testcase(0x2a6b6c96f75c1f071c4abf4ad4447355, 0x12d6d92e),
+        Testcase(0xb04b6d2548eef085a2575269cbc0d143, 0x9e96da4b),
+    ] {
+        testcase.verify();
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn extended_python_test_cases() {
+    // The inputs from ibm64ieee32's Python test cases, extended with a zero low-order fraction
+    for testcase in vec![
+        Testcase(0xe1e11a0a0add4c1ad300000000000000, 0xff800000),
+        Testcase(0xffff000000000000f100000000000000, 0xff800000),
+        Testcase(0xe1e11a0a0add4c1ad300000000000000, 0xff800000),
+        Testcase(0xcc4c00dd4c1a0000be00000000000000, 0xd69801bb),
+        Testcase(0x3b2600003b2600002d00000000000000, 0x34180001),
+        Testcase(0x4c4c4c4cd70a00003e00000000000000, 0x5698989a),
+        Testcase(0x4d4cffffffffffff3f00000000000000, 0x589a0000),
+        Testcase(0x2000ffffffffffff1200000000000000, 0x00002000),
+        Testcase(0x1b4c4c26282828280d00000000000000, 0x00000001),
+        Testcase(0x22010000fe4000041400000000000000, 0x00200020),
+        Testcase(0x00000000002600007200000000000000, 0x00000000),
+        Testcase(0x0000ff11ff11ff4c7200000000000000, 0x00000000),
+        Testcase(0x80000000000000fcf200000000000000, 0x80000000),
+        Testcase(0x8000000000260000f200000000000000, 0x80000000),
+        Testcase(0x00000000000000017200000000000000, 0x00000000),
+        Testcase(0x8000000000000001f200000000000000, 0x80000000),
+        Testcase(0x1effffffffffffff1000000000000000, 0x00002000),
+        Testcase(0x9effffffffffffff9000000000000000, 0x80002000),
+        Testcase(0x1fffffffffffffff1100000000000000, 0x00020000),
+        Testcase(0x9fffffffffffffff9100000000000000, 0x80020000),
+        Testcase(0x20ffffffffffffff1200000000000000, 0x00200000),
+        Testcase(0xa0ffffffffffffff9200000000000000, 0x80200000),
+        Testcase(0x213fffffbfffffff1300000000000000, 0x007fffff),
+        Testcase(0xa13fffffbfffffff9300000000000000, 0x807fffff),
+        Testcase(0x213fffffc00000001300000000000000, 0x00800000),
+        Testcase(0xa13fffffc00000009300000000000000, 0x80800000),
+        Testcase(0x213fffffffffffff1300000000000000, 0x00800000),
+        Testcase(0xa13fffffffffffff9300000000000000, 0x80800000),
+        Testcase(0x40ffffff7fffffff3200000000000000, 0x3f7fffff),
+        Testcase(0xc0ffffff7fffffffb200000000000000, 0xbf7fffff),
+        Testcase(0x40ffffff800000003200000000000000, 0x3f800000),
+        Testcase(0xc0ffffff80000000b200000000000000, 0xbf800000),
+        Testcase(0x411fffffefffffff3300000000000000, 0x3fffffff),
+        Testcase(0xc11fffffefffffffb300000000000000, 0xbfffffff),
+        Testcase(0x411ffffff00000003300000000000000, 0x40000000),
+        Testcase(0xc11ffffff0000000b300000000000000, 0xc0000000),
+        Testcase(0x411fffffffffffff3300000000000000, 0x40000000),
+        Testcase(0xc11fffffffffffffb300000000000000, 0xc0000000),
+        Testcase(0x60ffffff7fffffff5200000000000000, 0x7f7fffff),
+        Testcase(0xe0ffffff7fffffffd200000000000000, 0xff7fffff),
+        Testcase(0x60ffffff800000005200000000000000, 0x7f800000),
+        Testcase(0xe0ffffff80000000d200000000000000, 0xff800000),
+        Testcase(0x60ffffffffffffff5200000000000000, 0x7f800000),
+        Testcase(0xe0ffffffffffffffd200000000000000, 0xff800000),
+        Testcase(0x7fffffffffffffff7100000000000000, 0x7f800000),
+        Testcase(0xfffffffffffffffff100000000000000, 0xff800000),
+        Testcase(0x1da7bfffffffffff0f00000000000000, 0x0000014f),
+        Testcase(0x9da7bfffffffffff8f00000000000000, 0x8000014f),
+        Testcase(0x1da84000000000010f00000000000000, 0x00000151),
+        Testcase(0x9da84000000000018f00000000000000, 0x80000151),
+        Testcase(0x1da8bfffffffffff0f00000000000000, 0x00000151),
+        Testcase(0x9da8bfffffffffff8f00000000000000, 0x80000151),
+        Testcase(0x1da94000000000010f00000000000000, 0x00000153),
+        Testcase(0x9da94000000000018f00000000000000, 0x80000153),
+        Testcase(0x1da9bfffffffffff0f00000000000000, 0x00000153),
+        Testcase(0x9da9bfffffffffff8f00000000000000, 0x80000153),
+        Testcase(0x1daa4000000000010f00000000000000, 0x00000155),
+        Testcase(0x9daa4000000000018f00000000000000, 0x80000155),
+        Testcase(0x00000000000000000000000000000000, 0x00000000),
+        Testcase(0x8000000000000000f200000000000000, 0x80000000),
+        Testcase(0x00000001000000007200000000000000, 0x00000000),
+        Testcase(0x8000000100000000f200000000000000, 0x80000000),
+        Testcase(0x3f000000000000003100000000000000, 0x00000000),
+        Testcase(0xbf00000000000000b100000000000000, 0x80000000),
+        Testcase(0x7f000000000000007100000000000000, 0x00000000),
+        Testcase(0xff00000000000000f100000000000000, 0x80000000),
+        Testcase(0x1b100000000000000d00000000000000, 0x00000000),
+        Testcase(0x9b100000000000008d00000000000000, 0x80000000),
+        Testcase(0x1b200000000000000d00000000000000, 0x00000000),
+        Testcase(0x9b200000000000008d00000000000000, 0x80000000),
+        Testcase(0x1b400000000000000d00000000000000, 0x00000000),
+        Testcase(0x9b400000000000008d00000000000000, 0x80000000),
+        Testcase(0x1b400001000000000d00000000000000, 0x00000001),
+        Testcase(0x9b400001000000008d00000000000000, 0x80000001),
+        Testcase(0x1b800000000000000d00000000000000, 0x00000001),
+        Testcase(0x9b800000000000008d00000000000000, 0x80000001),
+        Testcase(0x1bbfffff000000000d00000000000000, 0x00000001),
+        Testcase(0x9bbfffff000000008d00000000000000, 0x80000001),
+        Testcase(0x1bc00000000000000d00000000000000, 0x00000002),
+        Testcase(0x9bc00000000000008d00000000000000, 0x80000002),
+        Testcase(0x1da7bfff000000000f00000000000000, 0x0000014f),
+        Testcase(0x9da7bfff000000008f00000000000000, 0x8000014f),
+        Testcase(0x1da7c000000000000f00000000000000, 0x00000150),
+        Testcase(0x9da7c000000000008f00000000000000, 0x80000150),
+        Testcase(0x1da84000000000000f00000000000000, 0x00000150),
+        Testcase(0x9da84000000000008f00000000000000, 0x80000150),
+        Testcase(0x1da84001000000000f00000000000000, 0x00000151),
+        Testcase(0x9da84001000000008f00000000000000, 0x80000151),
+        Testcase(0x1da8bfff000000000f00000000000000, 0x00000151),
+        Testcase(0x9da8bfff000000008f00000000000000, 0x80000151),
+        Testcase(0x1da8c000000000000f00000000000000, 0x00000152),
+        Testcase(0x9da8c000000000008f00000000000000, 0x80000152),
+        Testcase(0x1da94000000000000f00000000000000, 0x00000152),
+        Testcase(0x9da94000000000008f00000000000000, 0x80000152),
+        Testcase(0x1da94001000000000f00000000000000, 0x00000153),
+        Testcase(0x9da94001000000008f00000000000000, 0x80000153),
+        Testcase(0x1da9bfff000000000f00000000000000, 0x00000153),
+        Testcase(0x9da9bfff000000008f00000000000000, 0x80000153),
+        Testcase(0x1da9c000000000000f00000000000000, 0x00000154),
+        Testcase(0x9da9c000000000008f00000000000000, 0x80000154),
+        Testcase(0x1daa4000000000000f00000000000000, 0x00000154),
+        Testcase(0x9daa4000000000008f00000000000000, 0x80000154),
+        Testcase(0x1daa4001000000000f00000000000000, 0x00000155),
+        Testcase(0x9daa4001000000008f00000000000000, 0x80000155),
+        Testcase(0x1fffffff000000001100000000000000, 0x00020000),
+        Testcase(0x9fffffff000000009100000000000000, 0x80020000),
+        Testcase(0x20fffff4000000001200000000000000, 0x001ffffe),
+        Testcase(0xa0fffff4000000009200000000000000, 0x801ffffe),
+        Testcase(0x20fffff5000000001200000000000000, 0x001fffff),
+        Testcase(0xa0fffff5000000009200000000000000, 0x801fffff),
+        Testcase(0x20fffff6000000001200000000000000, 0x001fffff),
+        Testcase(0xa0fffff6000000009200000000000000, 0x801fffff),
+        Testcase(0x20fffff7000000001200000000000000, 0x001fffff),
+        Testcase(0xa0fffff7000000009200000000000000, 0x801fffff),
+        Testcase(0x20fffff8000000001200000000000000, 0x001fffff),
+        Testcase(0xa0fffff8000000009200000000000000, 0x801fffff),
+        Testcase(0x20fffff9000000001200000000000000, 0x001fffff),
+        Testcase(0xa0fffff9000000009200000000000000, 0x801fffff),
+        Testcase(0x20fffffa000000001200000000000000, 0x001fffff),
+        Testcase(0xa0fffffa000000009200000000000000, 0x801fffff),
+        Testcase(0x20fffffb000000001200000000000000, 0x001fffff),
+        Testcase(0xa0fffffb000000009200000000000000, 0x801fffff),
+        Testcase(0x20fffffc000000001200000000000000, 0x00200000),
+        Testcase(0xa0fffffc000000009200000000000000, 0x80200000),
+        Testcase(0x20fffffd000000001200000000000000, 0x00200000),
+        Testcase(0xa0fffffd000000009200000000000000, 0x80200000),
+        Testcase(0x20fffffe000000001200000000000000, 0x00200000),
+        Testcase(0xa0fffffe000000009200000000000000, 0x80200000),
+        Testcase(0x20ffffff000000001200000000000000, 0x00200000),
+        Testcase(0xa0ffffff000000009200000000000000, 0x80200000),
+        Testcase(0x21100000000000001300000000000000, 0x00200000),
+        Testcase(0xa1100000000000009300000000000000, 0x80200000),
+        Testcase(0x21200000000000001300000000000000, 0x00400000),
+        Testcase(0xa1200000000000009300000000000000, 0x80400000),
+        Testcase(0x213fffff000000001300000000000000, 0x007ffffe),
+        Testcase(0xa13fffff000000009300000000000000, 0x807ffffe),
+        Testcase(0x21400000000000001300000000000000, 0x00800000),
+        Testcase(0xa1400000000000009300000000000000, 0x80800000),
+        Testcase(0x40800000000000003200000000000000, 0x3f000000),
+        Testcase(0xc080000000000000b200000000000000, 0xbf000000),
+        Testcase(0x46000001000000003800000000000000, 0x3f800000),
+        Testcase(0xc600000100000000b800000000000000, 0xbf800000),
+        Testcase(0x45000010000000003700000000000000, 0x3f800000),
+        Testcase(0xc500001000000000b700000000000000, 0xbf800000),
+        Testcase(0x44000100000000003600000000000000, 0x3f800000),
+        Testcase(0xc400010000000000b600000000000000, 0xbf800000),
+        Testcase(0x43001000000000003500000000000000, 0x3f800000),
+        Testcase(0xc300100000000000b500000000000000, 0xbf800000),
+        Testcase(0x42010000000000003400000000000000, 0x3f800000),
+        Testcase(0xc201000000000000b400000000000000, 0xbf800000),
+        Testcase(0x41100000000000003300000000000000, 0x3f800000),
+        Testcase(0xc110000000000000b300000000000000, 0xbf800000),
+        Testcase(0x41200000000000003300000000000000, 0x40000000),
+        Testcase(0xc120000000000000b300000000000000, 0xc0000000),
+        Testcase(0x41300000000000003300000000000000, 0x40400000),
+        Testcase(0xc130000000000000b300000000000000, 0xc0400000),
+        Testcase(0x41400000000000003300000000000000, 0x40800000),
+        Testcase(0xc140000000000000b300000000000000, 0xc0800000),
+        Testcase(0x41800000000000003300000000000000, 0x41000000),
+        Testcase(0xc180000000000000b300000000000000, 0xc1000000),
+        Testcase(0x48000001000000003a00000000000000, 0x43800000),
+        Testcase(0xc800000100000000ba00000000000000, 0xc3800000),
+        Testcase(0x48000002000000003a00000000000000, 0x44000000),
+        Testcase(0xc800000200000000ba00000000000000, 0xc4000000),
+        Testcase(0x48000004000000003a00000000000000, 0x44800000),
+        Testcase(0xc800000400000000ba00000000000000, 0xc4800000),
+        Testcase(0x48000008000000003a00000000000000, 0x45000000),
+        Testcase(0xc800000800000000ba00000000000000, 0xc5000000),
+        Testcase(0x48000010000000003a00000000000000, 0x45800000),
+        Testcase(0xc800001000000000ba00000000000000, 0xc5800000),
+        Testcase(0x48000020000000003a00000000000000, 0x46000000),
+        Testcase(0xc800002000000000ba00000000000000, 0xc6000000),
+        Testcase(0x48000040000000003a00000000000000, 0x46800000),
+        Testcase(0xc800004000000000ba00000000000000, 0xc6800000),
+        Testcase(0x48000080000000003a00000000000000, 0x47000000),
+        Testcase(0xc800008000000000ba00000000000000, 0xc7000000),
+        Testcase(0x48000100000000003a00000000000000, 0x47800000),
+        Testcase(0xc800010000000000ba00000000000000, 0xc7800000),
+        Testcase(0x48000200000000003a00000000000000, 0x48000000),
+        Testcase(0xc800020000000000ba00000000000000, 0xc8000000),
+        Testcase(0x48000400000000003a00000000000000, 0x48800000),
+        Testcase(0xc800040000000000ba00000000000000, 0xc8800000),
+        Testcase(0x48000800000000003a00000000000000, 0x49000000),
+        Testcase(0xc800080000000000ba00000000000000, 0xc9000000),
+        Testcase(0x48001000000000003a00000000000000, 0x49800000),
+        Testcase(0xc800100000000000ba00000000000000, 0xc9800000),
+        Testcase(0x48002000000000003a00000000000000, 0x4a000000),
+        Testcase(0xc800200000000000ba00000000000000, 0xca000000),
+        Testcase(0x48004000000000003a00000000000000, 0x4a800000),
+        Testcase(0xc800400000000000ba00000000000000, 0xca800000),
+        Testcase(0x48008000000000003a00000000000000, 0x4b000000),
+        Testcase(0xc800800000000000ba00000000000000, 0xcb000000),
+        Testcase(0x48010000000000003a00000000000000, 0x4b800000),
+        Testcase(0xc801000000000000ba00000000000000, 0xcb800000),
+        Testcase(0x48020000000000003a00000000000000, 0x4c000000),
+        Testcase(0xc802000000000000ba00000000000000, 0xcc000000),
+        Testcase(0x48040000000000003a00000000000000, 0x4c800000),
+        Testcase(0xc804000000000000ba00000000000000, 0xcc800000),
+        Testcase(0x48080000000000003a00000000000000, 0x4d000000),
+        Testcase(0xc808000000000000ba00000000000000, 0xcd000000),
+        Testcase(0x48100000000000003a00000000000000, 0x4d800000),
+        Testcase(0xc810000000000000ba00000000000000, 0xcd800000),
+        Testcase(0x48200000000000003a00000000000000, 0x4e000000),
+        Testcase(0xc820000000000000ba00000000000000, 0xce000000),
+        Testcase(0x48400000000000003a00000000000000, 0x4e800000),
+        Testcase(0xc840000000000000ba00000000000000, 0xce800000),
+        Testcase(0x48800000000000003a00000000000000, 0x4f000000),
+        Testcase(0xc880000000000000ba00000000000000, 0xcf000000),
+        Testcase(0x60ffffff000000005200000000000000, 0x7f7fffff),
+        Testcase(0xe0ffffff00000000d200000000000000, 0xff7fffff),
+        Testcase(0x61100000000000005300000000000000, 0x7f800000),
+        Testcase(0xe110000000000000d300000000000000, 0xff800000),
+        Testcase(0x61200000000000005300000000000000, 0x7f800000),
+        Testcase(0xe120000000000000d300000000000000, 0xff800000),
+        Testcase(0x61400000000000005300000000000000, 0x7f800000),
+        Testcase(0xe140000000000000d300000000000000, 0xff800000),
+        Testcase(0x62100000000000005400000000000000, 0x7f800000),
+        Testcase(0xe210000000000000d400000000000000, 0xff800000),
+        Testcase(0x7fffffff000000007100000000000000, 0x7f800000),
+        Testcase(0xffffffff00000000f100000000000000, 0xff800000),
+        Testcase(0xc276a00000000000b400000000000000, 0xc2ed4000),
+        Testcase(0x4276a000000000003400000000000000, 0x42ed4000),
+    ] {
+        testcase.verify();
+    }
+}
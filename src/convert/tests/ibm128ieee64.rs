@@ -0,0 +1,410 @@
+use super::*;
+
+struct Testcase(u128, u64);
+impl Testcase {
+    fn verify(&self) {
+        let actual = ibm128ieee64(self.0);
+        assert_eq!(
+            actual, self.1,
+            "ibm128ieee64(0x{:032x}): got 0x{:016x}, expected 0x{:016x}",
+            self.0, actual, self.1
+        );
+    }
+}
+
+#[test]
+fn round_ties_to_even() {
+    for testcase in &[
+        Testcase(0x41100000000000003380000000000000, 0x3ff0000000000000),
+        Testcase(0x41100000000000003380000000000001, 0x3ff0000000000001),
+        Testcase(0x4110000000000000337fffffffffffff, 0x3ff0000000000000),
+        Testcase(0x41100000000000003300000000000001, 0x3ff0000000000000),
+        Testcase(0x411000000000000033ffffffffffffff, 0x3ff0000000000001),
+        Testcase(0x41100000000000013380000000000000, 0x3ff0000000000002),
+        Testcase(0x41100000000000013380000000000001, 0x3ff0000000000002),
+        Testcase(0x4110000000000001337fffffffffffff, 0x3ff0000000000001),
+        Testcase(0x41100000000000013300000000000001, 0x3ff0000000000001),
+        Testcase(0x411000000000000133ffffffffffffff, 0x3ff0000000000002),
+        Testcase(0xc110000000000000b380000000000000, 0xbff0000000000000),
+        Testcase(0xc110000000000000b380000000000001, 0xbff0000000000001),
+        Testcase(0xc110000000000000b37fffffffffffff, 0xbff0000000000000),
+        Testcase(0xc110000000000000b300000000000001, 0xbff0000000000000),
+        Testcase(0xc110000000000000b3ffffffffffffff, 0xbff0000000000001),
+        Testcase(0xc110000000000001b380000000000000, 0xbff0000000000002),
+        Testcase(0xc110000000000001b380000000000001, 0xbff0000000000002),
+        Testcase(0xc110000000000001b37fffffffffffff, 0xbff0000000000001),
+        Testcase(0xc110000000000001b300000000000001, 0xbff0000000000001),
+        Testcase(0xc110000000000001b3ffffffffffffff, 0xbff0000000000002),
+        Testcase(0x41800000000000003380000000000000, 0x4020000000000000),
+        Testcase(0x41800000000000003380000000000001, 0x4020000000000000),
+        Testcase(0x4180000000000000337fffffffffffff, 0x4020000000000000),
+        Testcase(0x41800000000000003300000000000001, 0x4020000000000000),
+        Testcase(0x418000000000000033ffffffffffffff, 0x4020000000000000),
+        Testcase(0x41800000000000083380000000000000, 0x4020000000000001),
+        Testcase(0x41800000000000083380000000000001, 0x4020000000000001),
+        Testcase(0x4180000000000008337fffffffffffff, 0x4020000000000001),
+        Testcase(0x41800000000000083300000000000001, 0x4020000000000001),
+        Testcase(0x418000000000000833ffffffffffffff, 0x4020000000000001),
+        Testcase(0x3f1234567890abcd3180000000000000, 0x3f7234567890abce),
+        Testcase(0x3f1234567890abcd3180000000000001, 0x3f7234567890abce),
+        Testcase(0x3f1234567890abcd317fffffffffffff, 0x3f7234567890abcd),
+        Testcase(0x3f1234567890abcd3100000000000001, 0x3f7234567890abcd),
+        Testcase(0x3f1234567890abcd31ffffffffffffff, 0x3f7234567890abce),
+        Testcase(0x41800000000000043300000000000000, 0x4020000000000000),
+        Testcase(0x41800000000000043300000000000001, 0x4020000000000001),
+        Testcase(0x41800000000000043380000000000000, 0x4020000000000001),
+        Testcase(0x418000000000000c3300000000000000, 0x4020000000000002),
+        Testcase(0x418000000000000c3300000000000001, 0x4020000000000002),
+        Testcase(0x418000000000000c3380000000000000, 0x4020000000000002),
+        Testcase(0xc18000000000000cb300000000000000, 0xc020000000000002),
+        Testcase(0xc18000000000000cb300000000000001, 0xc020000000000002),
+        Testcase(0xc18000000000000cb380000000000000, 0xc020000000000002),
+        Testcase(0x7ffffffffffffffc7100000000000000, 0x4fb0000000000000),
+        Testcase(0x7ffffffffffffffc7100000000000001, 0x4fb0000000000000),
+        Testcase(0x7ffffffffffffffc7180000000000000, 0x4fb0000000000000),
+        Testcase(0x00800000000000047200000000000000, 0x2fe0000000000000),
+        Testcase(0x00800000000000047200000000000001, 0x2fe0000000000001),
+        Testcase(0x00800000000000047280000000000000, 0x2fe0000000000001),
+    ] {
+        testcase.verify();
+    }
+}
+
+#[test]
+fn low_order_sign_and_characteristic_ignored() {
+    for testcase in &[
+        Testcase(0x3a37323d4ba9b9d43fd81ad164be72a0, 0x3e4b991ea5d4dcea),
+        Testcase(0xac89215d95e109b30ecaa4db91778ce5, 0xbae1242bb2bc2136),
+        Testcase(0xd1e0f03d6af56608cd5310ee7f260c70, 0xc42c1e07ad5eacc1),
+        Testcase(0xf24e2f8f4493bcf1d55116092a3afb37, 0xcc538be3d124ef3c),
+        Testcase(0x94965a8a86c810a675ca474707b5ce04, 0xb4e2cb5150d90215),
+        Testcase(0x893b153bd115924aa2d1d5ffc9822183, 0xb20d8a9de88ac925),
+        Testcase(0xfa082a26341d73ed2702d8151057a24e, 0xce20544c683ae7da),
+        Testcase(0x7e9aec603fab374c732fd74574678033, 0x4f635d8c07f566ea),
+        Testcase(0x3edfc47e5ee234de29f3874bca5bcfa5, 0x3f6bf88fcbdc469c),
+        Testcase(0xc899179c1cf3eb6b95bf2bd12795b457, 0xc1e322f3839e7d6d),
+    ] {
+        testcase.verify();
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn random_test_cases() {
+    for testcase in vec![
+        Testcase(0x9b95f239ae97d9db8def70b4177344a3, 0xb6a2be4735d2fb3b),
+        Testcase(0xaa59ce1e9e2936419cbf2eb1d7881003, 0xba567387a78a4d90),
+        Testcase(0xa269d132d5a5167b940912b883d833a9, 0xb85a744cb569459f),
+        Testcase(0xfbd5a293a18bb42fed700fdb5e85d8b3, 0xceaab45274317686),
+        Testcase(0xca032fa5872c3a65bcfb85a81b9c7ec8, 0xc2097d2c3961d330),
+        Testcase(0x74f47996aed5ce8066cba85999c566c6, 0x4cee8f32d5dab9d0),
+        Testcase(0xecde8b73482c3495def91ae560ea264f, 0xcaebd16e69058693),
+        Testcase(0x6c58bca5bc731dd65e24f74071a1f3d7, 0x4ad62f296f1cc776),
+        Testcase(0xccd8904ef5a965f9bee724fef8a43b14, 0xc2eb1209deb52cbf),
+        Testcase(0x0cacc82cd69eee907e69c160174d9406, 0x32e599059ad3ddd2),
+        Testcase(0x8b2de38c133d33eafdee00fe49e0c551, 0xb286f1c6099e99f5),
+        Testcase(0xa5f274ab5c718332976eae95b477caf2, 0xb92e4e956b8e3066),
+        Testcase(0x967978c9d3aed4c5885dbcb390082630, 0xb55e5e3274ebb531),
+        Testcase(0xb486d616a3a20685a6f86e1392b55aef, 0xbce0dac2d47440d1),
+        Testcase(0xce9da0dd08b7eeccc09e90f486d3692e, 0xc363b41ba116fdda),
+        Testcase(0x8154940b36972583f31022346abe13a4, 0xb0152502cda5c961),
+        Testcase(0x01f0a9d2023fabd2738297b9a7e14b71, 0x302e153a4047f57a),
+        Testcase(0x5c63ca78e90462784eb5084311907bf0, 0x46d8f29e3a41189e),
+        Testcase(0x39906b93947f41002b52eb4f0602dad6, 0x3e220d72728fe820),
+        Testcase(0x870a54c65a145979f9d225f928c6f444, 0xb164a98cb428b2f4),
+        Testcase(0x058e2f66dc4f19587703750fba5ff686, 0x3121c5ecdb89e32b),
+        Testcase(0xf13242d1e5835421e3bc606c37cc7efd, 0xcc092168f2c1aa11),
+        Testcase(0x4fc2afedb809cc36413a2ce0b3123cbf, 0x43a855fdb7013987),
+        Testcase(0xa640c41182bc1ec2984d3d33ea8bad8b, 0xb950310460af07b1),
+        Testcase(0x0ac5106d9ffb2a937c45c98a48661ccf, 0x3268a20db3ff6552),
+        Testcase(0x341efef6b79365382624a5267b325618, 0x3cbefef6b7936538),
+        Testcase(0x4432021b36894cb636a27a919e28777a, 0x40c9010d9b44a65b),
+        Testcase(0x70523a390c5c7e35621adf5672f60721, 0x4bd48e8e43171f8d),
+        Testcase(0xa613515f8e883cee984449e500a6579d, 0xb933515f8e883cee),
+        Testcase(0x3d38497ea608af492f717e5616940f34, 0x3f0c24bf530457a5),
+        Testcase(0x2154612aa655a5441359c545575448a1, 0x3815184aa9956951),
+        Testcase(0x190e4c80078a385f0b263a1dffd4160a, 0x35ec99000f1470be),
+        Testcase(0x93e5f936106d966d85a0cbf128029621, 0xb4acbf26c20db2ce),
+        Testcase(0xa861da6d8178cbbe9ad14ea93ebf7466, 0xb9d8769b605e32f0),
+        Testcase(0x49dd853087ca28603b06a9877959dbb2, 0x422bb0a610f9450c),
+        Testcase(0x2e080b5693979deb20e0cc818b2b24fe, 0x3b2016ad272f3bd8),
+        Testcase(0x22e10c4e4b52971c141ca91336221508, 0x386c2189c96a52e4),
+        Testcase(0x2a76d988b1cdca1f1c9468416dd1c5a2, 0x3a5db6622c737288),
+        Testcase(0x8a654bf985415de9fcaf6d8fea791f92, 0xb25952fe6150577a),
+        Testcase(0x5b7b00cb022c09d84d116bab46ffff5c, 0x469ec032c08b0276),
+        Testcase(0xd62444db903b0c18c83966ae0dd290cb, 0xc542226dc81d860c),
+        Testcase(0x2bf193bac104830f1d72458666bde56c, 0x3aae327758209062),
+        Testcase(0x11455af250fdab58031751ee31c66057, 0x341156bc943f6ad6),
+        Testcase(0x4f7f755af7807da14137a06c0beb2994, 0x439fdd56bde01f68),
+        Testcase(0x3c015873d24a76712e707d9eb45dc6c7, 0x3e75873d24a76717),
+        Testcase(0x907a94d6e5c1a6d8827f3eceb384cbde, 0xb3dea535b97069b6),
+        Testcase(0xc1549ba958d12196b3479d728ccb322f, 0xc01526ea56344866),
+        Testcase(0x993137c3ebef4ada8bd894440a77d0c2, 0xb6089be1f5f7a56d),
+        Testcase(0x8b2852b0d9964bf1fdceb935ac65dca2, 0xb28429586ccb25f9),
+        Testcase(0xb837fe293263e004aad82ce5f77c507f, 0xbdcbff149931f002),
+        Testcase(0xa3c3bf3a4cb0c31495f38a88012b06cc, 0xb8a877e749961863),
+        Testcase(0x946058fa447a22b086df970383e1bd34, 0xb4d8163e911e88ac),
+        Testcase(0x42a48f2f071d5f7634f72033977cd9c5, 0x406491e5e0e3abef),
+        Testcase(0x384242e071ce2f442afdbb01552ed6cd, 0x3dd090b81c738bd1),
+        Testcase(0xd3c44baec17379a0c55b662c9e4ceab6, 0xc4a88975d82e6f34),
+        Testcase(0x347d41fbf721025e26a2e5a9dea42b1c, 0x3cdf507efdc84098),
+        Testcase(0x5c84f436dc3295c14e1d0c9eeedcca76, 0x46e09e86db8652b8),
+        Testcase(0x5f4cfbc8d995d60b51f7a6617c980e99, 0x47933ef236657583),
+        Testcase(0xea68a7711184bb97dc9a7362727416e7, 0xca5a29dc44612ee6),
+        Testcase(0x8fd647f59e9d3170812a9c7ff82be4d6, 0xb3aac8feb3d3a62e),
+        Testcase(0xa7000000000000009905bbf4930339fc, 0xb5d6efd24c0ce7f0),
+        Testcase(0x16000000000000000800000000053427, 0x2f94d09c00000000),
+        Testcase(0x51000000000000004300000000000003, 0x3d48000000000000),
+        Testcase(0x24000000000000001600000000000005, 0x3214000000000000),
+        Testcase(0xbb00000000000000ad000000001e1f69, 0xb8fe1f6900000000),
+        Testcase(0x74000000000000006600000b935bf0a9, 0x482726b7e1520000),
+        Testcase(0x7d000000000000006f002ca6f0440505, 0x4b06537822028280),
+        Testcase(0x250000000000000017002b4a5e8c6c70, 0x3505a52f46363800),
+        Testcase(0xed00000000000000df0000000000054b, 0xc4d52c0000000000),
+        Testcase(0x28000000000000001a000000000079ae, 0x33de6b8000000000),
+        Testcase(0x5100000000000000430ada7d1397c47f, 0x4065b4fa272f88fe),
+        Testcase(0xf200000000000000e419b3bb9f63f689, 0xc8b9b3bb9f63f689),
+        Testcase(0x1b000000000000000d0000000001e96e, 0x30be96e000000000),
+        Testcase(0x38000000000000002a00000000000002, 0x3700000000000000),
+        Testcase(0xf600000000000000e80000000000005c, 0xc6d7000000000000),
+        Testcase(0x8c00000000000000fe00000000000001, 0xabf0000000000000),
+        Testcase(0xbd00000000000000af000000000002a5, 0xb8c5280000000000),
+        Testcase(0xe000000000000000d20003217a44c1e9, 0xc3890bd2260f4800),
+        Testcase(0x8a00000000000000fc00000000000015, 0xabb5000000000000),
+        Testcase(0x8b00000000000000fd0000000000001e, 0xabfe000000000000),
+    ] {
+        testcase.verify();
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn extended_python_test_cases() {
+    // The inputs from ibm64ieee64's Python test cases, extended with a zero low-order fraction
+    for testcase in vec![
+        Testcase(0x01f6b0aeaeaeffff7300000000000000, 0x302ed615d5d5e000),
+        Testcase(0x00730000000008737200000000000000, 0x2fdcc0000000021d),
+        Testcase(0xe39c9c9c9cffffffd500000000000000, 0xc8a3939393a00000),
+        Testcase(0xf6f5ffbf33bff595e800000000000000, 0xcd6ebff7e677feb3),
+        Testcase(0x00000000000000017200000000000000, 0x2c70000000000000),
+        Testcase(0x8000000000000001f200000000000000, 0xac70000000000000),
+        Testcase(0x00000000000000027200000000000000, 0x2c80000000000000),
+        Testcase(0x8000000000000002f200000000000000, 0xac80000000000000),
+        Testcase(0x00000000000000037200000000000000, 0x2c88000000000000),
+        Testcase(0x8000000000000003f200000000000000, 0xac88000000000000),
+        Testcase(0x400ffffffffffffe3200000000000000, 0x3faffffffffffffc),
+        Testcase(0xc00ffffffffffffeb200000000000000, 0xbfaffffffffffffc),
+        Testcase(0x400fffffffffffff3200000000000000, 0x3faffffffffffffe),
+        Testcase(0xc00fffffffffffffb200000000000000, 0xbfaffffffffffffe),
+        Testcase(0x40100000000000003200000000000000, 0x3fb0000000000000),
+        Testcase(0xc010000000000000b200000000000000, 0xbfb0000000000000),
+        Testcase(0x40100000000000013200000000000000, 0x3fb0000000000001),
+        Testcase(0xc010000000000001b200000000000000, 0xbfb0000000000001),
+        Testcase(0x40100000000000023200000000000000, 0x3fb0000000000002),
+        Testcase(0xc010000000000002b200000000000000, 0xbfb0000000000002),
+        Testcase(0x401ffffffffffffe3200000000000000, 0x3fbffffffffffffe),
+        Testcase(0xc01ffffffffffffeb200000000000000, 0xbfbffffffffffffe),
+        Testcase(0x401fffffffffffff3200000000000000, 0x3fbfffffffffffff),
+        Testcase(0xc01fffffffffffffb200000000000000, 0xbfbfffffffffffff),
+        Testcase(0x40200000000000003200000000000000, 0x3fc0000000000000),
+        Testcase(0xc020000000000000b200000000000000, 0xbfc0000000000000),
+        Testcase(0x40200000000000013200000000000000, 0x3fc0000000000000),
+        Testcase(0xc020000000000001b200000000000000, 0xbfc0000000000000),
+        Testcase(0x40200000000000023200000000000000, 0x3fc0000000000001),
+        Testcase(0xc020000000000002b200000000000000, 0xbfc0000000000001),
+        Testcase(0x40200000000000033200000000000000, 0x3fc0000000000002),
+        Testcase(0xc020000000000003b200000000000000, 0xbfc0000000000002),
+        Testcase(0x403ffffffffffffd3200000000000000, 0x3fcffffffffffffe),
+        Testcase(0xc03ffffffffffffdb200000000000000, 0xbfcffffffffffffe),
+        Testcase(0x403ffffffffffffe3200000000000000, 0x3fcfffffffffffff),
+        Testcase(0xc03ffffffffffffeb200000000000000, 0xbfcfffffffffffff),
+        Testcase(0x403fffffffffffff3200000000000000, 0x3fd0000000000000),
+        Testcase(0xc03fffffffffffffb200000000000000, 0xbfd0000000000000),
+        Testcase(0x40400000000000023200000000000000, 0x3fd0000000000000),
+        Testcase(0xc040000000000002b200000000000000, 0xbfd0000000000000),
+        Testcase(0x40400000000000033200000000000000, 0x3fd0000000000001),
+        Testcase(0xc040000000000003b200000000000000, 0xbfd0000000000001),
+        Testcase(0x40400000000000053200000000000000, 0x3fd0000000000001),
+        Testcase(0xc040000000000005b200000000000000, 0xbfd0000000000001),
+        Testcase(0x40400000000000063200000000000000, 0x3fd0000000000002),
+        Testcase(0xc040000000000006b200000000000000, 0xbfd0000000000002),
+        Testcase(0x407ffffffffffffa3200000000000000, 0x3fdffffffffffffe),
+        Testcase(0xc07ffffffffffffab200000000000000, 0xbfdffffffffffffe),
+        Testcase(0x407ffffffffffffb3200000000000000, 0x3fdfffffffffffff),
+        Testcase(0xc07ffffffffffffbb200000000000000, 0xbfdfffffffffffff),
+        Testcase(0x407ffffffffffffd3200000000000000, 0x3fdfffffffffffff),
+        Testcase(0xc07ffffffffffffdb200000000000000, 0xbfdfffffffffffff),
+        Testcase(0x407ffffffffffffe3200000000000000, 0x3fe0000000000000),
+        Testcase(0xc07ffffffffffffeb200000000000000, 0xbfe0000000000000),
+        Testcase(0x40800000000000043200000000000000, 0x3fe0000000000000),
+        Testcase(0xc080000000000004b200000000000000, 0xbfe0000000000000),
+        Testcase(0x40800000000000053200000000000000, 0x3fe0000000000001),
+        Testcase(0xc080000000000005b200000000000000, 0xbfe0000000000001),
+        Testcase(0x408000000000000b3200000000000000, 0x3fe0000000000001),
+        Testcase(0xc08000000000000bb200000000000000, 0xbfe0000000000001),
+        Testcase(0x408000000000000c3200000000000000, 0x3fe0000000000002),
+        Testcase(0xc08000000000000cb200000000000000, 0xbfe0000000000002),
+        Testcase(0x40fffffffffffff43200000000000000, 0x3feffffffffffffe),
+        Testcase(0xc0fffffffffffff4b200000000000000, 0xbfeffffffffffffe),
+        Testcase(0x40fffffffffffff53200000000000000, 0x3fefffffffffffff),
+        Testcase(0xc0fffffffffffff5b200000000000000, 0xbfefffffffffffff),
+        Testcase(0x40fffffffffffffb3200000000000000, 0x3fefffffffffffff),
+        Testcase(0xc0fffffffffffffbb200000000000000, 0xbfefffffffffffff),
+        Testcase(0x40fffffffffffffc3200000000000000, 0x3ff0000000000000),
+        Testcase(0xc0fffffffffffffcb200000000000000, 0xbff0000000000000),
+        Testcase(0x41100000000000003300000000000000, 0x3ff0000000000000),
+        Testcase(0xc110000000000000b300000000000000, 0xbff0000000000000),
+        Testcase(0x41100000000000013300000000000000, 0x3ff0000000000001),
+        Testcase(0xc110000000000001b300000000000000, 0xbff0000000000001),
+        Testcase(0x41100000000000023300000000000000, 0x3ff0000000000002),
+        Testcase(0xc110000000000002b300000000000000, 0xbff0000000000002),
+        Testcase(0x411fffffffffffff3300000000000000, 0x3fffffffffffffff),
+        Testcase(0xc11fffffffffffffb300000000000000, 0xbfffffffffffffff),
+        Testcase(0x41200000000000003300000000000000, 0x4000000000000000),
+        Testcase(0xc120000000000000b300000000000000, 0xc000000000000000),
+        Testcase(0x41200000000000013300000000000000, 0x4000000000000000),
+        Testcase(0xc120000000000001b300000000000000, 0xc000000000000000),
+        Testcase(0x41200000000000023300000000000000, 0x4000000000000001),
+        Testcase(0xc120000000000002b300000000000000, 0xc000000000000001),
+        Testcase(0x48000000000000013a00000000000000, 0x3e70000000000000),
+        Testcase(0xc800000000000001ba00000000000000, 0xbe70000000000000),
+        Testcase(0x48000000000000023a00000000000000, 0x3e80000000000000),
+        Testcase(0xc800000000000002ba00000000000000, 0xbe80000000000000),
+        Testcase(0x48000000000000043a00000000000000, 0x3e90000000000000),
+        Testcase(0xc800000000000004ba00000000000000, 0xbe90000000000000),
+        Testcase(0x48000000000000083a00000000000000, 0x3ea0000000000000),
+        Testcase(0xc800000000000008ba00000000000000, 0xbea0000000000000),
+        Testcase(0x48000000000000103a00000000000000, 0x3eb0000000000000),
+        Testcase(0xc800000000000010ba00000000000000, 0xbeb0000000000000),
+        Testcase(0x48000000000000203a00000000000000, 0x3ec0000000000000),
+        Testcase(0xc800000000000020ba00000000000000, 0xbec0000000000000),
+        Testcase(0x48000000000000403a00000000000000, 0x3ed0000000000000),
+        Testcase(0xc800000000000040ba00000000000000, 0xbed0000000000000),
+        Testcase(0x48000000000000803a00000000000000, 0x3ee0000000000000),
+        Testcase(0xc800000000000080ba00000000000000, 0xbee0000000000000),
+        Testcase(0x48000000000001003a00000000000000, 0x3ef0000000000000),
+        Testcase(0xc800000000000100ba00000000000000, 0xbef0000000000000),
+        Testcase(0x48000000000002003a00000000000000, 0x3f00000000000000),
+        Testcase(0xc800000000000200ba00000000000000, 0xbf00000000000000),
+        Testcase(0x48000000000004003a00000000000000, 0x3f10000000000000),
+        Testcase(0xc800000000000400ba00000000000000, 0xbf10000000000000),
+        Testcase(0x48000000000008003a00000000000000, 0x3f20000000000000),
+        Testcase(0xc800000000000800ba00000000000000, 0xbf20000000000000),
+        Testcase(0x48000000000010003a00000000000000, 0x3f30000000000000),
+        Testcase(0xc800000000001000ba00000000000000, 0xbf30000000000000),
+        Testcase(0x48000000000020003a00000000000000, 0x3f40000000000000),
+        Testcase(0xc800000000002000ba00000000000000, 0xbf40000000000000),
+        Testcase(0x48000000000040003a00000000000000, 0x3f50000000000000),
+        Testcase(0xc800000000004000ba00000000000000, 0xbf50000000000000),
+        Testcase(0x48000000000080003a00000000000000, 0x3f60000000000000),
+        Testcase(0xc800000000008000ba00000000000000, 0xbf60000000000000),
+        Testcase(0x48000000000100003a00000000000000, 0x3f70000000000000),
+        Testcase(0xc800000000010000ba00000000000000, 0xbf70000000000000),
+        Testcase(0x48000000000200003a00000000000000, 0x3f80000000000000),
+        Testcase(0xc800000000020000ba00000000000000, 0xbf80000000000000),
+        Testcase(0x48000000000400003a00000000000000, 0x3f90000000000000),
+        Testcase(0xc800000000040000ba00000000000000, 0xbf90000000000000),
+        Testcase(0x48000000000800003a00000000000000, 0x3fa0000000000000),
+        Testcase(0xc800000000080000ba00000000000000, 0xbfa0000000000000),
+        Testcase(0x48000000001000003a00000000000000, 0x3fb0000000000000),
+        Testcase(0xc800000000100000ba00000000000000, 0xbfb0000000000000),
+        Testcase(0x48000000002000003a00000000000000, 0x3fc0000000000000),
+        Testcase(0xc800000000200000ba00000000000000, 0xbfc0000000000000),
+        Testcase(0x48000000004000003a00000000000000, 0x3fd0000000000000),
+        Testcase(0xc800000000400000ba00000000000000, 0xbfd0000000000000),
+        Testcase(0x48000000008000003a00000000000000, 0x3fe0000000000000),
+        Testcase(0xc800000000800000ba00000000000000, 0xbfe0000000000000),
+        Testcase(0x48000000010000003a00000000000000, 0x3ff0000000000000),
+        Testcase(0xc800000001000000ba00000000000000, 0xbff0000000000000),
+        Testcase(0x48000000020000003a00000000000000, 0x4000000000000000),
+        Testcase(0xc800000002000000ba00000000000000, 0xc000000000000000),
+        Testcase(0x48000000040000003a00000000000000, 0x4010000000000000),
+        Testcase(0xc800000004000000ba00000000000000, 0xc010000000000000),
+        Testcase(0x48000000080000003a00000000000000, 0x4020000000000000),
+        Testcase(0xc800000008000000ba00000000000000, 0xc020000000000000),
+        Testcase(0x48000000100000003a00000000000000, 0x4030000000000000),
+        Testcase(0xc800000010000000ba00000000000000, 0xc030000000000000),
+        Testcase(0x48000000200000003a00000000000000, 0x4040000000000000),
+        Testcase(0xc800000020000000ba00000000000000, 0xc040000000000000),
+        Testcase(0x48000000400000003a00000000000000, 0x4050000000000000),
+        Testcase(0xc800000040000000ba00000000000000, 0xc050000000000000),
+        Testcase(0x48000000800000003a00000000000000, 0x4060000000000000),
+        Testcase(0xc800000080000000ba00000000000000, 0xc060000000000000),
+        Testcase(0x48000001000000003a00000000000000, 0x4070000000000000),
+        Testcase(0xc800000100000000ba00000000000000, 0xc070000000000000),
+        Testcase(0x48000002000000003a00000000000000, 0x4080000000000000),
+        Testcase(0xc800000200000000ba00000000000000, 0xc080000000000000),
+        Testcase(0x48000004000000003a00000000000000, 0x4090000000000000),
+        Testcase(0xc800000400000000ba00000000000000, 0xc090000000000000),
+        Testcase(0x48000008000000003a00000000000000, 0x40a0000000000000),
+        Testcase(0xc800000800000000ba00000000000000, 0xc0a0000000000000),
+        Testcase(0x48000010000000003a00000000000000, 0x40b0000000000000),
+        Testcase(0xc800001000000000ba00000000000000, 0xc0b0000000000000),
+        Testcase(0x48000020000000003a00000000000000, 0x40c0000000000000),
+        Testcase(0xc800002000000000ba00000000000000, 0xc0c0000000000000),
+        Testcase(0x48000040000000003a00000000000000, 0x40d0000000000000),
+        Testcase(0xc800004000000000ba00000000000000, 0xc0d0000000000000),
+        Testcase(0x48000080000000003a00000000000000, 0x40e0000000000000),
+        Testcase(0xc800008000000000ba00000000000000, 0xc0e0000000000000),
+        Testcase(0x48000100000000003a00000000000000, 0x40f0000000000000),
+        Testcase(0xc800010000000000ba00000000000000, 0xc0f0000000000000),
+        Testcase(0x48000200000000003a00000000000000, 0x4100000000000000),
+        Testcase(0xc800020000000000ba00000000000000, 0xc100000000000000),
+        Testcase(0x48000400000000003a00000000000000, 0x4110000000000000),
+        Testcase(0xc800040000000000ba00000000000000, 0xc110000000000000),
+        Testcase(0x48000800000000003a00000000000000, 0x4120000000000000),
+        Testcase(0xc800080000000000ba00000000000000, 0xc120000000000000),
+        Testcase(0x48001000000000003a00000000000000, 0x4130000000000000),
+        Testcase(0xc800100000000000ba00000000000000, 0xc130000000000000),
+        Testcase(0x48002000000000003a00000000000000, 0x4140000000000000),
+        Testcase(0xc800200000000000ba00000000000000, 0xc140000000000000),
+        Testcase(0x48004000000000003a00000000000000, 0x4150000000000000),
+        Testcase(0xc800400000000000ba00000000000000, 0xc150000000000000),
+        Testcase(0x48008000000000003a00000000000000, 0x4160000000000000),
+        Testcase(0xc800800000000000ba00000000000000, 0xc160000000000000),
+        Testcase(0x48010000000000003a00000000000000, 0x4170000000000000),
+        Testcase(0xc801000000000000ba00000000000000, 0xc170000000000000),
+        Testcase(0x48020000000000003a00000000000000, 0x4180000000000000),
+        Testcase(0xc802000000000000ba00000000000000, 0xc180000000000000),
+        Testcase(0x48040000000000003a00000000000000, 0x4190000000000000),
+        Testcase(0xc804000000000000ba00000000000000, 0xc190000000000000),
+        Testcase(0x48080000000000003a00000000000000, 0x41a0000000000000),
+        Testcase(0xc808000000000000ba00000000000000, 0xc1a0000000000000),
+        Testcase(0x48100000000000003a00000000000000, 0x41b0000000000000),
+        Testcase(0xc810000000000000ba00000000000000, 0xc1b0000000000000),
+        Testcase(0x48200000000000003a00000000000000, 0x41c0000000000000),
+        Testcase(0xc820000000000000ba00000000000000, 0xc1c0000000000000),
+        Testcase(0x48400000000000003a00000000000000, 0x41d0000000000000),
+        Testcase(0xc840000000000000ba00000000000000, 0xc1d0000000000000),
+        Testcase(0x48800000000000003a00000000000000, 0x41e0000000000000),
+        Testcase(0xc880000000000000ba00000000000000, 0xc1e0000000000000),
+        Testcase(0x567faef3ff3dc2824800000000000000, 0x455febbcffcf70a0),
+        Testcase(0xd67faef3ff3dc282c800000000000000, 0xc55febbcffcf70a0),
+        Testcase(0x7ffffffffffffff47100000000000000, 0x4faffffffffffffe),
+        Testcase(0xfffffffffffffff4f100000000000000, 0xcfaffffffffffffe),
+        Testcase(0x7ffffffffffffff57100000000000000, 0x4fafffffffffffff),
+        Testcase(0xfffffffffffffff5f100000000000000, 0xcfafffffffffffff),
+        Testcase(0x7ffffffffffffffb7100000000000000, 0x4fafffffffffffff),
+        Testcase(0xfffffffffffffffbf100000000000000, 0xcfafffffffffffff),
+        Testcase(0x7ffffffffffffffc7100000000000000, 0x4fb0000000000000),
+        Testcase(0xfffffffffffffffcf100000000000000, 0xcfb0000000000000),
+        Testcase(0x7ffffffffffffffd7100000000000000, 0x4fb0000000000000),
+        Testcase(0xfffffffffffffffdf100000000000000, 0xcfb0000000000000),
+        Testcase(0x7ffffffffffffffe7100000000000000, 0x4fb0000000000000),
+        Testcase(0xfffffffffffffffef100000000000000, 0xcfb0000000000000),
+        Testcase(0x7fffffffffffffff7100000000000000, 0x4fb0000000000000),
+        Testcase(0xfffffffffffffffff100000000000000, 0xcfb0000000000000),
+        Testcase(0x00000000000000000000000000000000, 0x0000000000000000),
+        Testcase(0x8000000000000000f200000000000000, 0x8000000000000000),
+        Testcase(0x00000001000000007200000000000000, 0x2e70000000000000),
+        Testcase(0x8000000100000000f200000000000000, 0xae70000000000000),
+        Testcase(0x00ffffff000000007200000000000000, 0x2fefffffe0000000),
+        Testcase(0x80ffffff00000000f200000000000000, 0xafefffffe0000000),
+        Testcase(0x41100000000000003300000000000000, 0x3ff0000000000000),
+        Testcase(0xc110000000000000b300000000000000, 0xbff0000000000000),
+        Testcase(0x7fffffff000000007100000000000000, 0x4fafffffe0000000),
+        Testcase(0xffffffff00000000f100000000000000, 0xcfafffffe0000000),
+    ] {
+        testcase.verify();
+    }
+}
@@ -1,5 +1,7 @@
 use super::*;
 
+mod ibm128ieee32;
+mod ibm128ieee64;
 mod ibm32ieee32;
 mod ibm32ieee64;
 mod ibm64ieee32;
@@ -0,0 +1,37 @@
+#[cfg(feature = "std")]
+use std::fmt;
+
+/// An exceptional condition raised by an operation on IBM floating point numbers.
+///
+/// These correspond to the hexadecimal floating point program exceptions described in IBM's
+/// Principles of Operation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Exception {
+    /// The result's characteristic would exceed 127.
+    ExponentOverflow,
+    /// The result's characteristic would be less than zero.
+    ExponentUnderflow,
+    /// The fraction of a sum or difference is zero.
+    Significance,
+    /// The divisor's fraction is zero.
+    FloatingPointDivide,
+    /// The operand of a square root is negative.
+    SquareRoot,
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for Exception {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Exception::ExponentOverflow => "exponent overflow",
+            Exception::ExponentUnderflow => "exponent underflow",
+            Exception::Significance => "significance",
+            Exception::FloatingPointDivide => "floating-point divide",
+            Exception::SquareRoot => "square root",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Exception {}
@@ -0,0 +1,254 @@
+#[cfg(not(feature = "std"))]
+use core::cmp;
+#[cfg(feature = "std")]
+use std::{cmp, fmt};
+
+use crate::compare::Normalized;
+use crate::convert::{self, split_hex};
+use crate::{F32, F64};
+
+/// An IBM-style hexadecimal floating point number of `BITS` total bits.
+///
+/// Some systems stored excess-64 hex floats in words other than 32 or 64 bits wide. `HexFloat`
+/// describes any such format with a sign bit, a 7-bit characteristic, and a fraction of
+/// `(BITS - 8) / 4` hex digits, right-aligned in a `u64`. `BITS` must be a multiple of 4 between 12
+/// and 64; other widths fail to compile.
+///
+/// ```compile_fail
+/// let foreign_float = ibmfloat::HexFloat::<34>::from_bits(0);
+/// ```
+///
+/// [`F32`](struct.F32.html) and [`F64`](struct.F64.html) are the standard instances: they share
+/// their encodings with `HexFloat<32>` and `HexFloat<64>`, convert to and from them via
+/// `From`/`Into`, and use their conversions to native floats and their exact comparisons.
+///
+/// This type supports the conversions:
+///
+/// * Transmuting to/from a right-aligned `u64` via `from_bits()`, `to_bits()`
+/// * Reading from a big-endian byte slice via `from_be_slice()`
+/// * Lossily converting to an `f32` via `From`/`Into` or the const `to_f32()`
+/// * Lossily converting to an `f64` via `From`/`Into` or the const `to_f64()`
+///
+/// Conversions are correctly rounded, using round-ties-to-even.
+///
+/// ```
+/// use ibmfloat::HexFloat;
+///
+/// // A 48-bit word holding 10 hex digits
+/// let foreign_float = HexFloat::<48>::from_bits(0xc276_a000_0000);
+///
+/// let native_float = f64::from(foreign_float);
+/// assert_eq!(native_float, -118.625f64);
+/// ```
+///
+/// `HexFloat`s compare exactly, just like `F32` and `F64`.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct HexFloat<const BITS: u32>(u64);
+
+impl<const BITS: u32> HexFloat<BITS> {
+    /// Fails to compile when `BITS` does not describe a valid format.
+    const VALID: () = assert!(
+        BITS >= 12 && BITS <= 64 && BITS & 3 == 0,
+        "HexFloat requires a multiple of 4 bits between 12 and 64"
+    );
+
+    /// The total size of this format, in bits.
+    pub const BITS: u32 = BITS;
+
+    /// The number of hexadecimal digits in this format's fraction.
+    pub const FRACTION_DIGITS: u32 = (BITS - 8) / 4;
+
+    /// Transmute a right-aligned native-endian `u64` into a `HexFloat`.
+    ///
+    /// Bits above the format's width are ignored.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::HexFloat::<40>::from_bits(0x41_1000_0000);
+    /// assert_eq!(f64::from(foreign_float), 1.0f64);
+    ///
+    /// let foreign_float = ibmfloat::HexFloat::<40>::from_bits(0xff41_1000_0000);
+    /// assert_eq!(foreign_float.to_bits(), 0x41_1000_0000);
+    /// ```
+    #[inline]
+    pub const fn from_bits(value: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        Self(value & (u64::MAX >> (64 - BITS)))
+    }
+
+    /// Transmute this `HexFloat` to a right-aligned native-endian `u64`.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::HexFloat::<40>::from_bits(0x41_1000_0000);
+    /// assert_eq!(foreign_float.to_bits(), 0x41_1000_0000);
+    /// ```
+    #[inline]
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    /// Create a floating point value from its big-endian representation in a byte slice.
+    ///
+    /// Returns `None` unless `BITS` is a multiple of 8 and `bytes` is exactly `BITS / 8` bytes
+    /// long.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::HexFloat::<48>::from_be_slice(&[0x41, 0x10, 0, 0, 0, 0]);
+    /// assert_eq!(foreign_float.map(f64::from), Some(1.0f64));
+    ///
+    /// assert!(ibmfloat::HexFloat::<48>::from_be_slice(&[0x41, 0x10, 0, 0]).is_none());
+    /// ```
+    pub fn from_be_slice(bytes: &[u8]) -> Option<Self> {
+        if BITS & 7 != 0 || bytes.len() != BITS as usize / 8 {
+            return None;
+        }
+
+        let mut buffer = [0u8; 8];
+        buffer[8 - bytes.len()..].copy_from_slice(bytes);
+        Some(Self::from_bits(u64::from_be_bytes(buffer)))
+    }
+
+    /// Convert this `HexFloat` to the nearest `f32`, rounding ties to even.
+    ///
+    /// This is the same conversion as `f32::from()`, usable in const contexts.
+    ///
+    /// ```
+    /// const NATIVE_FLOAT: f32 = ibmfloat::HexFloat::<48>::from_bits(0xc276_a000_0000).to_f32();
+    /// assert_eq!(NATIVE_FLOAT, -118.625f32);
+    /// ```
+    #[inline]
+    pub const fn to_f32(self) -> f32 {
+        f32::from_bits(if BITS <= 32 {
+            convert::ibm32ieee32(convert::ibmhex_ibm32::<BITS>(self.0))
+        } else {
+            convert::ibm64ieee32(convert::ibmhex_ibm64::<BITS>(self.0))
+        })
+    }
+
+    /// Convert this `HexFloat` to the nearest `f64`, rounding ties to even.
+    ///
+    /// This is the same conversion as `f64::from()`, usable in const contexts.
+    ///
+    /// ```
+    /// const NATIVE_FLOAT: f64 = ibmfloat::HexFloat::<48>::from_bits(0xc276_a000_0000).to_f64();
+    /// assert_eq!(NATIVE_FLOAT, -118.625f64);
+    /// ```
+    #[inline]
+    pub const fn to_f64(self) -> f64 {
+        f64::from_bits(if BITS <= 32 {
+            convert::ibm32ieee64(convert::ibmhex_ibm32::<BITS>(self.0))
+        } else {
+            convert::ibm64ieee64(convert::ibmhex_ibm64::<BITS>(self.0))
+        })
+    }
+
+    /// Widen to an `F64` of exactly the same value.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::HexFloat::<40>::from_bits(0x41_1000_0000);
+    /// assert_eq!(foreign_float.to_f64_exact().to_bits(), 0x4110_0000_0000_0000);
+    /// ```
+    #[inline]
+    pub const fn to_f64_exact(self) -> F64 {
+        F64::from_bits(convert::ibmhex_ibm64::<BITS>(self.0))
+    }
+}
+
+impl<const BITS: u32> From<HexFloat<BITS>> for f32 {
+    #[inline]
+    fn from(v: HexFloat<BITS>) -> Self {
+        v.to_f32()
+    }
+}
+
+impl<const BITS: u32> From<HexFloat<BITS>> for f64 {
+    #[inline]
+    fn from(v: HexFloat<BITS>) -> Self {
+        v.to_f64()
+    }
+}
+
+impl From<F32> for HexFloat<32> {
+    #[inline]
+    fn from(v: F32) -> Self {
+        Self::from_bits(u64::from(v.to_bits()))
+    }
+}
+
+impl From<HexFloat<32>> for F32 {
+    #[inline]
+    fn from(v: HexFloat<32>) -> Self {
+        F32::from_bits(v.0 as u32)
+    }
+}
+
+impl From<F64> for HexFloat<64> {
+    #[inline]
+    fn from(v: F64) -> Self {
+        Self::from_bits(v.to_bits())
+    }
+}
+
+impl From<HexFloat<64>> for F64 {
+    #[inline]
+    fn from(v: HexFloat<64>) -> Self {
+        F64::from_bits(v.0)
+    }
+}
+
+impl<const BITS: u32> From<HexFloat<BITS>> for Normalized {
+    #[inline]
+    fn from(v: HexFloat<BITS>) -> Self {
+        let (sign, characteristic, fraction) = split_hex::<BITS>(v.0);
+        Self::new(
+            sign != 0,
+            characteristic as i32,
+            (fraction as u128) << (136 - BITS),
+        )
+    }
+}
+
+// Convert everything to an f64 and implement Debug and Display over top
+
+#[cfg(feature = "std")]
+impl<const BITS: u32> fmt::Debug for HexFloat<BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f64::from(*self).fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const BITS: u32> fmt::Display for HexFloat<BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f64::from(*self).fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const BITS: u32> fmt::LowerExp for HexFloat<BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f64::from(*self).fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const BITS: u32> fmt::UpperExp for HexFloat<BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f64::from(*self).fmt(f)
+    }
+}
+
+// Compare the exact values, which f64 cannot always represent
+
+impl<const BITS: u32> PartialEq for HexFloat<BITS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(cmp::Ordering::Equal)
+    }
+}
+
+impl<const BITS: u32> PartialOrd for HexFloat<BITS> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(Normalized::from(*self).cmp(&Normalized::from(*other)))
+    }
+}
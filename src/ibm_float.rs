@@ -0,0 +1,131 @@
+#[cfg(not(feature = "std"))]
+use core::{fmt, hash};
+#[cfg(feature = "std")]
+use std::{fmt, hash};
+
+use crate::{F128, F32, F64};
+
+/// Functionality common to every IBM floating point format, allowing code to be written once and
+/// used with any of [`F32`](struct.F32.html), [`F64`](struct.F64.html), or
+/// [`F128`](struct.F128.html).
+///
+/// ```
+/// use ibmfloat::{IbmFloat, F32, F64};
+///
+/// fn decode<T: IbmFloat>(bytes: &[u8]) -> Vec<f64> {
+///     bytes
+///         .chunks_exact(T::BITS as usize / 8)
+///         .map(|chunk| {
+///             let mut bytes = T::Bytes::default();
+///             bytes.as_mut().copy_from_slice(chunk);
+///             T::from_be_bytes(bytes).to_f64()
+///         })
+///         .collect()
+/// }
+///
+/// let bytes = [0x41, 0x10, 0, 0, 0xc2, 0x76, 0xa0, 0];
+/// assert_eq!(decode::<F32>(&bytes), [1.0, -118.625]);
+///
+/// let bytes = [0x41, 0x10, 0, 0, 0, 0, 0, 0];
+/// assert_eq!(decode::<F64>(&bytes), [1.0]);
+/// ```
+pub trait IbmFloat: Copy + PartialEq + PartialOrd + Into<f32> + Into<f64> {
+    /// The unsigned integer type holding this format's bits.
+    type Bits: Copy + Default + Eq + Ord + hash::Hash + fmt::Debug + fmt::LowerHex + fmt::UpperHex;
+
+    /// The byte array type holding this format's memory representation.
+    type Bytes: Copy + Default + Eq + hash::Hash + fmt::Debug + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// The total size of this format, in bits.
+    const BITS: u32;
+
+    /// The number of hexadecimal digits in this format's fraction.
+    const FRACTION_DIGITS: u32;
+
+    /// The radix of this format's exponent.
+    const RADIX: u32 = 16;
+
+    /// The excess added to the exponent to form the characteristic.
+    const EXPONENT_BIAS: i32 = 64;
+
+    /// The largest finite value.
+    const MAX: Self;
+
+    /// The smallest positive normalized value.
+    const MIN_POSITIVE: Self;
+
+    /// Transmute a native-endian integer into a value.
+    fn from_bits(bits: Self::Bits) -> Self;
+
+    /// Transmute this value into a native-endian integer.
+    fn to_bits(self) -> Self::Bits;
+
+    /// Create a value from its representation as a byte array in big endian.
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Return the memory representation of this value as a byte array in big-endian byte order.
+    fn to_be_bytes(self) -> Self::Bytes;
+
+    /// Convert this value to the nearest `f32`.
+    #[inline]
+    fn to_f32(self) -> f32 {
+        self.into()
+    }
+
+    /// Convert this value to the nearest `f64`.
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.into()
+    }
+}
+
+macro_rules! ibm_float {
+    ($t:ident, $U:ty, $N:expr, $digits:expr, $max:expr, $min_positive:expr) => {
+        impl IbmFloat for $t {
+            type Bits = $U;
+            type Bytes = [u8; $N];
+
+            const BITS: u32 = $N * 8;
+            const FRACTION_DIGITS: u32 = $digits;
+            const MAX: Self = $t::from_bits($max);
+            const MIN_POSITIVE: Self = $t::from_bits($min_positive);
+
+            #[inline]
+            fn from_bits(bits: $U) -> Self {
+                $t::from_bits(bits)
+            }
+
+            #[inline]
+            fn to_bits(self) -> $U {
+                $t::to_bits(self)
+            }
+
+            #[inline]
+            fn from_be_bytes(bytes: [u8; $N]) -> Self {
+                $t::from_be_bytes(bytes)
+            }
+
+            #[inline]
+            fn to_be_bytes(self) -> [u8; $N] {
+                $t::to_be_bytes(self)
+            }
+        }
+    };
+}
+ibm_float!(F32, u32, 4, 6, 0x7fff_ffff, 0x0010_0000);
+ibm_float!(
+    F64,
+    u64,
+    8,
+    14,
+    0x7fff_ffff_ffff_ffff,
+    0x0010_0000_0000_0000
+);
+ibm_float!(
+    F128,
+    u128,
+    16,
+    28,
+    0x7fff_ffff_ffff_ffff_71ff_ffff_ffff_ffff,
+    0x0010_0000_0000_0000_7200_0000_0000_0000
+);
@@ -15,7 +15,16 @@
 //! numbers](https://en.wikipedia.org/wiki/IBM_hexadecimal_floating_point), specifically focused on
 //! converting them to IEEE-754 floating point values.
 //!
-//! See [`F32`](struct.F32.html) for 32-bit floats and [`F64`](struct.F64.html) for 64-bit floats.
+//! See [`F32`](struct.F32.html) for 32-bit floats, [`F64`](struct.F64.html) for 64-bit floats, and
+//! [`F128`](struct.F128.html) for 128-bit extended-precision floats.
+//! [`F32Be`](struct.F32Be.html) and [`F64Be`](struct.F64Be.html) store the same values as
+//! byte-aligned big-endian arrays. With the `bytemuck` feature, they can be viewed in place within
+//! byte buffers.
+//! [`IbmFloat`](trait.IbmFloat.html) abstracts over the formats, so generic code can be written
+//! once.
+//! [`HexFloat`](struct.HexFloat.html) covers non-standard widths, such as 40- or 48-bit words.
+//! [`C32`](struct.C32.html) and [`C64`](struct.C64.html) hold complex numbers made of pairs of
+//! `F32`s or `F64`s.
 //!
 //! ## Usage
 //!
@@ -25,8 +34,11 @@
 //!
 //! * Transmuting to/from a `u32` via `from_bits()`, `to_bits()`
 //! * Transmuting to/from a big-endian `[u8; 4]` via `from_be_bytes()`/`to_be_bytes()`
-//! * Lossily converting to an `f32` via `From`/`Into`
-//! * Losslessly converting to an `f64` via `From`/`Into`
+//! * Transmuting to/from a `[u8; 4]` in little-endian, native, or any `ByteOrder` via
+//!   `from_le_bytes()`, `from_ne_bytes()`, `from_bytes()` and their `to_*` counterparts
+//! * Lossily converting to an `f32` via `From`/`Into` or the const `to_f32()`
+//! * Losslessly converting to an `f64` via `From`/`Into` or the const `to_f64()`
+//! * Exactly decomposing into an integer mantissa and base 2 exponent via `integer_decode()`
 //!
 //! IBM `F32` floats have slightly less precision than IEEE-754 `f32` floats, but it covers a slightly larger domain. `F32`s
 //! of typical magnitude can be converted to `f32` without rounding or other loss of precision. Converting `F32`s of large
@@ -53,8 +65,11 @@
 //!
 //! * Transmuting to/from a `u64` via `from_bits()`, `to_bits()`
 //! * Transmuting to/from a big-endian `[u8; 8]` via `from_be_bytes()`/`to_be_bytes()`
-//! * Lossily converting to an `f32` via `From`/`Into`
-//! * Lossily converting to an `f64` via `From`/`Into`
+//! * Transmuting to/from a `[u8; 8]` in little-endian, native, or any `ByteOrder` via
+//!   `from_le_bytes()`, `from_ne_bytes()`, `from_bytes()` and their `to_*` counterparts
+//! * Lossily converting to an `f32` via `From`/`Into` or the const `to_f32()`
+//! * Lossily converting to an `f64` via `From`/`Into` or the const `to_f64()`
+//! * Exactly decomposing into an integer mantissa and base 2 exponent via `integer_decode()`
 //!
 //! IBM `F64` floats have slightly more precision than IEEE-754 `f64` floats, but they cover a slightly smaller domain. Most
 //! conversions will require rounding, but there is no risk of overflow or underflow.
@@ -68,6 +83,50 @@
 //! let native_float: f64 = foreign_float.into();
 //! assert_eq!(native_float, 1.0f64);
 //! ```
+//!
+//! ### 128-bit floats
+//!
+//! `ibmfloat::F128` represents a 128-bit IBM extended-precision floating point number. It supports the conversions:
+//!
+//! * Transmuting to/from a `u128` via `from_bits()`, `to_bits()`
+//! * Transmuting to/from a big-endian `[u8; 16]` via `from_be_bytes()`/`to_be_bytes()`
+//! * Transmuting to/from a `[u8; 16]` in little-endian or native byte order via `from_le_bytes()`, `from_ne_bytes()`
+//!   and their `to_*` counterparts
+//! * Lossily converting to an `f32` via `From`/`Into` or the const `to_f32()`
+//! * Lossily converting to an `f64` via `From`/`Into` or the const `to_f64()`
+//!
+//! The low-order half of an `F128` carries its own sign and characteristic, which are ignored by conversions and
+//! comparisons. `is_valid()` checks that they match what the hardware would store.
+//!
+//! ```rust
+//! let foreign_float = ibmfloat::F128::from_bits(0x4110000000000000_3300000000000000);
+//! assert!(foreign_float.is_valid());
+//!
+//! let native_float = f64::from(foreign_float);
+//! assert_eq!(native_float, 1.0f64);
+//! ```
+//!
+//! ### Arithmetic
+//!
+//! `F32` and `F64` support `+`, `-`, `*`, and `/` with the exact results of the hardware's hexadecimal floating point
+//! instructions, including the single guard digit and truncated results, rather than IEEE-754 arithmetic on converted
+//! values. This allows results computed on a mainframe to be reproduced bit for bit. `mul_wide()`, `mul_add()`,
+//! `mul_sub()`, and `sqrt()` likewise emulate the untruncated multiply, multiply-and-add, and square root instructions.
+//!
+//! The operators behave as if the program mask had the exponent underflow and significance masks clear. `HfpContext`
+//! performs the same operations under either mask, recording the exceptions the hardware would report in sticky flags.
+//!
+//! `compare()`, `load_and_test()`, `load_complement()`, `load_positive()`, and `load_negative()` return the
+//! `ConditionCode` the hardware would set, for driving branches exactly as the original program did. `halve()` and
+//! `load_rounded()` emulate the remaining load-family instructions, which leave the condition code unchanged.
+//!
+//! ```rust
+//! use ibmfloat::F32;
+//!
+//! let one = F32::from_bits(0x41100000);
+//! let almost_one = F32::from_bits(0x40ffffff);
+//! assert_eq!((one - almost_one).to_bits(), 0x3b100000);
+//! ```
 
 #[cfg(feature = "std")]
 use std::{cmp, fmt};
@@ -75,7 +134,36 @@ use std::{cmp, fmt};
 #[cfg(not(feature = "std"))]
 use core::cmp;
 
+mod arithmetic;
+mod bits;
+mod byte_order;
+mod compare;
+mod complex;
+mod condition;
+mod context;
 mod convert;
+mod exception;
+mod hex_float;
+mod ibm_float;
+mod ops;
+#[cfg(feature = "num-rational")]
+mod rational;
+mod scale;
+mod sign;
+mod sort;
+mod total_order;
+mod unaligned;
+
+pub use bits::Bits;
+pub use byte_order::ByteOrder;
+pub use complex::{C32, C64};
+pub use condition::ConditionCode;
+pub use context::{HfpArithmetic, HfpContext, HfpRounding, HfpWidening};
+pub use exception::Exception;
+pub use hex_float::HexFloat;
+pub use ibm_float::IbmFloat;
+pub use total_order::TotalOrder;
+pub use unaligned::{F32Be, F64Be};
 
 /// A 32-bit IBM floating point number.
 ///
@@ -83,8 +171,11 @@ mod convert;
 ///
 /// * Transmuting to/from a `u32` via `from_bits()`, `to_bits()`
 /// * Transmuting to/from a big-endian `[u8; 4]` via `from_be_bytes()`/`to_be_bytes()`
-/// * Lossily converting to an `f32` via `From`/`Into`
-/// * Losslessly converting to an `f64` via `From`/`Into`
+/// * Transmuting to/from a `[u8; 4]` in little-endian, native, or any `ByteOrder` via
+///   `from_le_bytes()`, `from_ne_bytes()`, `from_bytes()` and their `to_*` counterparts
+/// * Lossily converting to an `f32` via `From`/`Into` or the const `to_f32()`
+/// * Losslessly converting to an `f64` via `From`/`Into` or the const `to_f64()`
+/// * Exactly decomposing into an integer mantissa and base 2 exponent via `integer_decode()`
 ///
 /// IBM `F32` floats have slightly less precision than IEEE-754 `f32` floats, but it covers a
 /// slightly larger domain. `F32`s of typical magnitude can be converted to `f32` without rounding
@@ -94,6 +185,17 @@ mod convert;
 /// Every `F32` can be precisely represented as an `f64`, without rounding, overflow, or underflow.
 /// Those seeking a lossless path to IEEE-754 should convert `F32` to `f64`.
 ///
+/// `F32`s compare exactly, using their sign, characteristic, and fraction rather than a rounded
+/// IEEE-754 value. Unnormalized encodings compare equal to their normalized equivalents, and all
+/// zeros compare equal to each other, regardless of their sign or characteristic. `F32`s can also
+/// be compared exactly against `F32`, `F64`, `f32`, and `f64` values without converting first.
+///
+/// `F32`s support `+`, `-`, `*`, and `/`, producing bit-for-bit the results of the hardware's
+/// hexadecimal floating point instructions rather than IEEE-754 arithmetic on converted values. The
+/// operators behave as if the exponent underflow and significance masks were clear, so underflows,
+/// zero sums, and zero products produce true zeros, while overflows leave a characteristic 128 too
+/// small. Division returns a `Result`, failing on division by zero.
+///
 /// ```
 /// // Use the example -118.625:
 /// //   https://en.wikipedia.org/wiki/IBM_hexadecimal_floating_point#Example
@@ -165,6 +267,108 @@ impl F32 {
     pub const fn to_be_bytes(self) -> [u8; 4] {
         self.0.to_be_bytes()
     }
+
+    /// Create a floating point value from its representation as a byte array in little endian.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F32::from_le_bytes([1, 0, 0, 0x46]);
+    ///
+    /// assert_eq!(foreign_float.to_bits(), 0x46000001);
+    /// ```
+    #[inline]
+    pub const fn from_le_bytes(bytes: [u8; 4]) -> Self {
+        Self(u32::from_le_bytes(bytes))
+    }
+
+    /// Return the memory representation of this floating point number as a byte array in
+    /// little-endian byte order.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F32::from_bits(0x46000001);
+    ///
+    /// assert_eq!(foreign_float.to_le_bytes(), [1, 0, 0, 0x46]);
+    /// ```
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+
+    /// Create a floating point value from its representation as a byte array in native endian.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F32::from_ne_bytes(if cfg!(target_endian = "big") {
+    ///     [0x46, 0, 0, 1]
+    /// } else {
+    ///     [1, 0, 0, 0x46]
+    /// });
+    ///
+    /// assert_eq!(foreign_float.to_bits(), 0x46000001);
+    /// ```
+    #[inline]
+    pub const fn from_ne_bytes(bytes: [u8; 4]) -> Self {
+        Self(u32::from_ne_bytes(bytes))
+    }
+
+    /// Return the memory representation of this floating point number as a byte array in native
+    /// byte order.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F32::from_bits(0x46000001);
+    ///
+    /// assert_eq!(foreign_float.to_ne_bytes(), foreign_float.to_bits().to_ne_bytes());
+    /// ```
+    #[inline]
+    pub const fn to_ne_bytes(self) -> [u8; 4] {
+        self.0.to_ne_bytes()
+    }
+
+    /// Decompose this `F32` into its integer mantissa, base 2 exponent, and sign.
+    ///
+    /// The original value is exactly `sign * mantissa * 2^exponent`. The mantissa is the raw
+    /// fraction field and is not normalized, so unnormalized encodings decompose differently than
+    /// their normalized equivalents, and zeros return a mantissa of `0`.
+    ///
+    /// ```
+    /// // -118.625
+    /// let foreign_float = ibmfloat::F32::from_bits(0xc276a000);
+    ///
+    /// let (mantissa, exponent, sign) = foreign_float.integer_decode();
+    /// assert_eq!((mantissa, exponent, sign), (0x76a000, -16, -1));
+    /// assert_eq!(sign as f64 * mantissa as f64 * 2f64.powi(exponent as i32), -118.625);
+    /// ```
+    #[inline]
+    pub const fn integer_decode(self) -> (u64, i16, i8) {
+        let sign = if self.0 >> 31 == 0 { 1 } else { -1 };
+        let exponent = ((self.0 >> 24) & 0x7f) as i16;
+        let mantissa = (self.0 & 0x00ff_ffff) as u64;
+        (mantissa, (exponent - 64) * 4 - 24, sign)
+    }
+
+    /// Convert this `F32` to the nearest `f32`, rounding ties to even.
+    ///
+    /// This is the same conversion as `f32::from()`, usable in const contexts.
+    ///
+    /// ```
+    /// const NATIVE_FLOAT: f32 = ibmfloat::F32::from_bits(0xc276a000).to_f32();
+    /// assert_eq!(NATIVE_FLOAT, -118.625f32);
+    /// ```
+    #[inline]
+    pub const fn to_f32(self) -> f32 {
+        HexFloat::<32>::from_bits(self.0 as u64).to_f32()
+    }
+
+    /// Convert this `F32` to an `f64`, which represents every `F32` exactly.
+    ///
+    /// This is the same conversion as `f64::from()`, usable in const contexts.
+    ///
+    /// ```
+    /// const NATIVE_FLOAT: f64 = ibmfloat::F32::from_bits(0xc276a000).to_f64();
+    /// assert_eq!(NATIVE_FLOAT, -118.625f64);
+    /// ```
+    #[inline]
+    pub const fn to_f64(self) -> f64 {
+        HexFloat::<32>::from_bits(self.0 as u64).to_f64()
+    }
 }
 
 /// A 64-bit IBM floating point number.
@@ -173,13 +377,27 @@ impl F32 {
 ///
 /// * Transmuting to/from a `u64` via `from_bits()`, `to_bits()`
 /// * Transmuting to/from a big-endian `[u8; 8]` via `from_be_bytes()`/`to_be_bytes()`
-/// * Lossily converting to an `f32` via `From`/`Into`
-/// * Lossily converting to an `f64` via `From`/`Into`
+/// * Transmuting to/from a `[u8; 8]` in little-endian, native, or any `ByteOrder` via
+///   `from_le_bytes()`, `from_ne_bytes()`, `from_bytes()` and their `to_*` counterparts
+/// * Lossily converting to an `f32` via `From`/`Into` or the const `to_f32()`
+/// * Lossily converting to an `f64` via `From`/`Into` or the const `to_f64()`
+/// * Exactly decomposing into an integer mantissa and base 2 exponent via `integer_decode()`
 ///
 /// IBM `F64` floats have slightly more precision than IEEE-754 `f64` floats, but they cover a
 /// slightly smaller domain. Most conversions will require rounding, but there is no risk of
 /// overflow or underflow.
 ///
+/// `F64`s compare exactly, using their sign, characteristic, and fraction rather than a rounded
+/// IEEE-754 value. Unnormalized encodings compare equal to their normalized equivalents, and all
+/// zeros compare equal to each other, regardless of their sign or characteristic. `F64`s can also
+/// be compared exactly against `F32`, `F64`, `f32`, and `f64` values without converting first.
+///
+/// `F64`s support `+`, `-`, `*`, and `/`, producing bit-for-bit the results of the hardware's
+/// hexadecimal floating point instructions rather than IEEE-754 arithmetic on converted values. The
+/// operators behave as if the exponent underflow and significance masks were clear, so underflows,
+/// zero sums, and zero products produce true zeros, while overflows leave a characteristic 128 too
+/// small. Division returns a `Result`, failing on division by zero.
+///
 /// ```
 /// let foreign_float = ibmfloat::F64::from_bits(0x4110000000000000);
 ///
@@ -246,11 +464,336 @@ impl F64 {
     pub const fn to_be_bytes(self) -> [u8; 8] {
         self.0.to_be_bytes()
     }
+
+    /// Create a floating point value from its representation as a byte array in little endian.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F64::from_le_bytes([0, 0, 0, 0, 0, 0, 0x10, 0x41]);
+    ///
+    /// assert_eq!(foreign_float.to_bits(), 0x4110000000000000);
+    /// ```
+    #[inline]
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Self(u64::from_le_bytes(bytes))
+    }
+
+    /// Return the memory representation of this floating point number as a byte array in
+    /// little-endian byte order.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F64::from_bits(0x4110000000000000);
+    ///
+    /// assert_eq!(foreign_float.to_le_bytes(), [0, 0, 0, 0, 0, 0, 0x10, 0x41]);
+    /// ```
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    /// Create a floating point value from its representation as a byte array in native endian.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F64::from_ne_bytes(if cfg!(target_endian = "big") {
+    ///     [0x41, 0x10, 0, 0, 0, 0, 0, 0]
+    /// } else {
+    ///     [0, 0, 0, 0, 0, 0, 0x10, 0x41]
+    /// });
+    ///
+    /// assert_eq!(foreign_float.to_bits(), 0x4110000000000000);
+    /// ```
+    #[inline]
+    pub const fn from_ne_bytes(bytes: [u8; 8]) -> Self {
+        Self(u64::from_ne_bytes(bytes))
+    }
+
+    /// Return the memory representation of this floating point number as a byte array in native
+    /// byte order.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F64::from_bits(0x4110000000000000);
+    ///
+    /// assert_eq!(foreign_float.to_ne_bytes(), foreign_float.to_bits().to_ne_bytes());
+    /// ```
+    #[inline]
+    pub const fn to_ne_bytes(self) -> [u8; 8] {
+        self.0.to_ne_bytes()
+    }
+
+    /// Decompose this `F64` into its integer mantissa, base 2 exponent, and sign.
+    ///
+    /// The original value is exactly `sign * mantissa * 2^exponent`. Unlike converting to `f64`,
+    /// no rounding occurs, making this useful as an exact reference. The mantissa is the raw
+    /// fraction field and is not normalized, so unnormalized encodings decompose differently than
+    /// their normalized equivalents, and zeros return a mantissa of `0`.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F64::from_bits(0x4110000000000001);
+    ///
+    /// let (mantissa, exponent, sign) = foreign_float.integer_decode();
+    /// assert_eq!((mantissa, exponent, sign), (0x10000000000001, -52, 1));
+    /// ```
+    #[inline]
+    pub const fn integer_decode(self) -> (u64, i16, i8) {
+        let sign = if self.0 >> 63 == 0 { 1 } else { -1 };
+        let exponent = ((self.0 >> 56) & 0x7f) as i16;
+        let mantissa = self.0 & 0x00ff_ffff_ffff_ffff;
+        (mantissa, (exponent - 64) * 4 - 56, sign)
+    }
+
+    /// Convert this `F64` to the nearest `f32`, rounding ties to even.
+    ///
+    /// This is the same conversion as `f32::from()`, usable in const contexts.
+    ///
+    /// ```
+    /// const NATIVE_FLOAT: f32 = ibmfloat::F64::from_bits(0x4110000000000000).to_f32();
+    /// assert_eq!(NATIVE_FLOAT, 1.0f32);
+    /// ```
+    #[inline]
+    pub const fn to_f32(self) -> f32 {
+        HexFloat::<64>::from_bits(self.0).to_f32()
+    }
+
+    /// Convert this `F64` to the nearest `f64`, rounding ties to even.
+    ///
+    /// This is the same conversion as `f64::from()`, usable in const contexts.
+    ///
+    /// ```
+    /// const NATIVE_FLOAT: f64 = ibmfloat::F64::from_bits(0x4110000000000000).to_f64();
+    /// assert_eq!(NATIVE_FLOAT, 1.0f64);
+    /// ```
+    #[inline]
+    pub const fn to_f64(self) -> f64 {
+        HexFloat::<64>::from_bits(self.0).to_f64()
+    }
+}
+
+/// A 128-bit IBM extended-precision floating point number.
+///
+/// This type supports the conversions:
+///
+/// * Transmuting to/from a `u128` via `from_bits()`, `to_bits()`
+/// * Transmuting to/from a big-endian `[u8; 16]` via `from_be_bytes()`/`to_be_bytes()`
+/// * Transmuting to/from a `[u8; 16]` in little-endian or native byte order via
+///   `from_le_bytes()`, `from_ne_bytes()`, and their `to_*` counterparts
+/// * Lossily converting to an `f32` via `From`/`Into` or the const `to_f32()`
+/// * Lossily converting to an `f64` via `From`/`Into` or the const `to_f64()`
+///
+/// The extended format is a pair of 64-bit IBM floats. The high-order part holds the sign, the
+/// characteristic, and the leading 14 hex digits of the fraction. The low-order part holds the
+/// trailing 14 hex digits of the fraction, and also has its own sign and characteristic, which the
+/// hardware sets to match the high-order sign and to 14 less than the high-order characteristic.
+/// The low-order sign and characteristic are ignored when converting or comparing; see
+/// [`is_valid()`](#method.is_valid) to check them.
+///
+/// IBM `F128` floats cover the same domain as `F64` floats, with more than twice the precision.
+/// Conversions to `f64` will nearly always require rounding, but there is no risk of overflow or
+/// underflow.
+///
+/// `F128`s compare exactly, using their sign, characteristic, and fraction rather than a rounded
+/// IEEE-754 value. Unnormalized encodings compare equal to their normalized equivalents, and all
+/// zeros compare equal to each other, regardless of their sign or characteristic.
+///
+/// ```
+/// let foreign_float = ibmfloat::F128::from_bits(0x4110000000000000_3300000000000000);
+///
+/// let native_float = f64::from(foreign_float);
+/// assert_eq!(native_float, 1.0f64);
+///
+/// let native_float: f64 = foreign_float.into();
+/// assert_eq!(native_float, 1.0f64);
+/// ```
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct F128(u128);
+
+impl F128 {
+    /// Transmute a native-endian `u128` into an `F128`.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F128::from_bits(0x4110000000000000_3300000000000000);
+    ///
+    /// let native_float = f64::from(foreign_float);
+    /// assert_eq!(native_float, 1.0f64);
+    /// ```
+    #[inline]
+    pub const fn from_bits(value: u128) -> Self {
+        Self(value)
+    }
+
+    /// Transmute this `F128` to a native-endian `u128`.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F128::from_bits(0x4110000000000000_3300000000000000);
+    ///
+    /// assert_eq!(foreign_float.to_bits(), 0x4110000000000000_3300000000000000);
+    /// ```
+    #[inline]
+    pub const fn to_bits(self) -> u128 {
+        self.0
+    }
+
+    /// Create a floating point value from its representation as a byte array in big endian.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F128::from_be_bytes([
+    ///     0x41, 0x10, 0, 0, 0, 0, 0, 0, 0x33, 0, 0, 0, 0, 0, 0, 0,
+    /// ]);
+    ///
+    /// assert_eq!(foreign_float.to_bits(), 0x4110000000000000_3300000000000000);
+    ///
+    /// let native_float = f64::from(foreign_float);
+    /// assert_eq!(native_float, 1.0f64);
+    /// ```
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_be_bytes(bytes))
+    }
+
+    /// Return the memory representation of this floating point number as a byte array in big-endian
+    /// (network) byte order.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F128::from_bits(0x4110000000000000_3300000000000000);
+    ///
+    /// assert_eq!(
+    ///     foreign_float.to_be_bytes(),
+    ///     [0x41, 0x10, 0, 0, 0, 0, 0, 0, 0x33, 0, 0, 0, 0, 0, 0, 0]
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; 16] {
+        self.0.to_be_bytes()
+    }
+
+    /// Create a floating point value from its representation as a byte array in little endian.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F128::from_le_bytes([
+    ///     0, 0, 0, 0, 0, 0, 0, 0x33, 0, 0, 0, 0, 0, 0, 0x10, 0x41,
+    /// ]);
+    ///
+    /// assert_eq!(foreign_float.to_bits(), 0x4110000000000000_3300000000000000);
+    /// ```
+    #[inline]
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_le_bytes(bytes))
+    }
+
+    /// Return the memory representation of this floating point number as a byte array in
+    /// little-endian byte order.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F128::from_bits(0x4110000000000000_3300000000000000);
+    ///
+    /// assert_eq!(
+    ///     foreign_float.to_le_bytes(),
+    ///     [0, 0, 0, 0, 0, 0, 0, 0x33, 0, 0, 0, 0, 0, 0, 0x10, 0x41]
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; 16] {
+        self.0.to_le_bytes()
+    }
+
+    /// Create a floating point value from its representation as a byte array in native endian.
+    ///
+    /// ```
+    /// let bits = 0x4110000000000000_3300000000000000u128;
+    /// let foreign_float = ibmfloat::F128::from_ne_bytes(bits.to_ne_bytes());
+    ///
+    /// assert_eq!(foreign_float.to_bits(), bits);
+    /// ```
+    #[inline]
+    pub const fn from_ne_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_ne_bytes(bytes))
+    }
+
+    /// Return the memory representation of this floating point number as a byte array in native
+    /// byte order.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F128::from_bits(0x4110000000000000_3300000000000000);
+    ///
+    /// assert_eq!(foreign_float.to_ne_bytes(), foreign_float.to_bits().to_ne_bytes());
+    /// ```
+    #[inline]
+    pub const fn to_ne_bytes(self) -> [u8; 16] {
+        self.0.to_ne_bytes()
+    }
+
+    /// Returns `true` if the low-order sign and characteristic are those the hardware would store.
+    ///
+    /// The low-order sign must match the high-order sign, and the low-order characteristic must be
+    /// 14 less than the high-order characteristic, modulo 128. The exception is a value whose bits
+    /// are all zero apart from the low-order sign and characteristic, i.e. a positive true zero,
+    /// for which the low-order sign and characteristic must also be zero.
+    ///
+    /// ```
+    /// use ibmfloat::F128;
+    ///
+    /// assert!(F128::from_bits(0x4110000000000000_3300000000000000).is_valid());
+    /// assert!(F128::from_bits(0xc110000000000000_b300000000000000).is_valid());
+    /// assert!(F128::from_bits(0x0000000000000000_0000000000000000).is_valid());
+    /// assert!(F128::from_bits(0x0010000000000000_7200000000000000).is_valid());
+    ///
+    /// assert!(!F128::from_bits(0x4110000000000000_0000000000000000).is_valid());
+    /// assert!(!F128::from_bits(0x4110000000000000_b300000000000000).is_valid());
+    /// assert!(!F128::from_bits(0x0000000000000000_8000000000000000).is_valid());
+    /// ```
+    #[inline]
+    pub const fn is_valid(self) -> bool {
+        self.0 == Self::with_low_order_part(self.0)
+    }
+
+    /// Convert this `F128` to the nearest `f32`, rounding ties to even.
+    ///
+    /// This is the same conversion as `f32::from()`, usable in const contexts.
+    ///
+    /// ```
+    /// const FOREIGN_FLOAT: ibmfloat::F128 =
+    ///     ibmfloat::F128::from_bits(0x4110000000000000_3300000000000000);
+    /// const NATIVE_FLOAT: f32 = FOREIGN_FLOAT.to_f32();
+    /// assert_eq!(NATIVE_FLOAT, 1.0f32);
+    /// ```
+    #[inline]
+    pub const fn to_f32(self) -> f32 {
+        f32::from_bits(convert::ibm128ieee32(self.0))
+    }
+
+    /// Convert this `F128` to the nearest `f64`, rounding ties to even.
+    ///
+    /// This is the same conversion as `f64::from()`, usable in const contexts.
+    ///
+    /// ```
+    /// const FOREIGN_FLOAT: ibmfloat::F128 =
+    ///     ibmfloat::F128::from_bits(0x4110000000000000_3300000000000000);
+    /// const NATIVE_FLOAT: f64 = FOREIGN_FLOAT.to_f64();
+    /// assert_eq!(NATIVE_FLOAT, 1.0f64);
+    /// ```
+    #[inline]
+    pub const fn to_f64(self) -> f64 {
+        f64::from_bits(convert::ibm128ieee64(self.0))
+    }
+
+    /// Replace the low-order sign and characteristic of an extended float with those the hardware
+    /// would store.
+    pub(crate) const fn with_low_order_part(bits: u128) -> u128 {
+        const LOW_ORDER: u128 = 0xff00_0000_0000_0000;
+        let bits = bits & !LOW_ORDER;
+        if bits == 0 {
+            return 0;
+        }
+
+        let high = (bits >> 64) as u64;
+        let sign = (high >> 63) as u128;
+        let characteristic = ((high >> 56) as u128).wrapping_sub(14) & 0x7f;
+        bits | sign << 63 | characteristic << 56
+    }
 }
 
 macro_rules! float {
     ($t:ty) => {
-        // Convert everything to an f64 and implement Debug, PartialEq, PartialOrd over top
+        // Convert everything to an f64 and implement Debug and Display over top
 
         #[cfg(feature = "std")]
         impl fmt::Debug for $t {
@@ -280,49 +823,68 @@ macro_rules! float {
             }
         }
 
+        // Compare the exact values, which f64 cannot always represent
+
         impl PartialEq for $t {
             fn eq(&self, other: &Self) -> bool {
-                f64::from(*self).eq(&f64::from(*other))
+                self.partial_cmp(other) == Some(cmp::Ordering::Equal)
             }
         }
 
         impl PartialOrd for $t {
             fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-                f64::from(*self).partial_cmp(&f64::from(*other))
+                Some(compare::Normalized::from(*self).cmp(&compare::Normalized::from(*other)))
             }
         }
     };
 }
 float!(F32);
 float!(F64);
+float!(F128);
 
 impl From<F32> for f32 {
     #[inline]
     fn from(v: F32) -> Self {
-        f32::from_bits(convert::ibm32ieee32(v.0))
+        v.to_f32()
     }
 }
 
 impl From<F32> for f64 {
     #[inline]
     fn from(v: F32) -> Self {
-        f64::from_bits(convert::ibm32ieee64(v.0))
+        v.to_f64()
     }
 }
 
 impl From<F64> for f32 {
     #[inline]
     fn from(v: F64) -> Self {
-        f32::from_bits(convert::ibm64ieee32(v.0))
+        v.to_f32()
     }
 }
 
 impl From<F64> for f64 {
     #[inline]
     fn from(v: F64) -> Self {
-        f64::from_bits(convert::ibm64ieee64(v.0))
+        v.to_f64()
+    }
+}
+
+impl From<F128> for f32 {
+    #[inline]
+    fn from(v: F128) -> Self {
+        v.to_f32()
+    }
+}
+
+impl From<F128> for f64 {
+    #[inline]
+    fn from(v: F128) -> Self {
+        v.to_f64()
     }
 }
 
 #[cfg(all(test, feature = "std"))]
 mod std_tests;
+#[cfg(test)]
+mod tests;
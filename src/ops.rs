@@ -0,0 +1,733 @@
+//! Arithmetic operators with the results of IBM's hexadecimal floating point instructions.
+//!
+//! The operators behave as a program running with the exponent underflow and significance masks
+//! clear: exponent underflow, zero sums, and zero products produce true zeros. Exponent overflow
+//! produces a characteristic 128 too small, just like the hardware, which would also raise a
+//! program interruption. Division by zero is suppressed by the hardware, so `Div` returns a
+//! `Result`, and there is no `DivAssign`.
+
+#[cfg(not(feature = "std"))]
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+#[cfg(feature = "std")]
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use crate::arithmetic::{self, Hfp, Masks};
+use crate::{ConditionCode, Exception, F128, F32, F64};
+
+macro_rules! ops {
+    ($t:ident, $U:ty, $digits:expr) => {
+        impl $t {
+            #[inline]
+            const fn unpack(self) -> Hfp {
+                Hfp::unpack(self.0 as u128, $digits)
+            }
+
+            #[inline]
+            const fn pack(value: Hfp) -> Self {
+                Self(value.pack($digits) as $U)
+            }
+        }
+
+        impl Add for $t {
+            type Output = Self;
+
+            /// Add two values as ADD NORMALIZED does, with one guard digit and a truncated result.
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                let (sum, _) =
+                    arithmetic::add(self.unpack(), rhs.unpack(), $digits, Masks::CLEAR, true);
+                Self::pack(sum)
+            }
+        }
+
+        impl Sub for $t {
+            type Output = Self;
+
+            /// Subtract two values as SUBTRACT NORMALIZED does, with one guard digit and a
+            /// truncated result.
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                self + -rhs
+            }
+        }
+
+        impl Mul for $t {
+            type Output = Self;
+
+            /// Multiply two values as MULTIPLY does, normalizing the operands and truncating the
+            /// product.
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                let (product, _) =
+                    arithmetic::mul(self.unpack(), rhs.unpack(), $digits, Masks::CLEAR);
+                Self::pack(product)
+            }
+        }
+
+        impl Div for $t {
+            type Output = Result<Self, Exception>;
+
+            /// Divide two values as DIVIDE does, normalizing the operands and truncating the
+            /// quotient.
+            ///
+            /// Division by a zero, including a dirty zero, returns
+            /// `Err(Exception::FloatingPointDivide)`, since the hardware suppresses the operation.
+            #[inline]
+            fn div(self, rhs: Self) -> Result<Self, Exception> {
+                match arithmetic::div(self.unpack(), rhs.unpack(), $digits, Masks::CLEAR) {
+                    (_, Some(Exception::FloatingPointDivide)) => {
+                        Err(Exception::FloatingPointDivide)
+                    }
+                    (quotient, _) => Ok(Self::pack(quotient)),
+                }
+            }
+        }
+
+        impl AddAssign for $t {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $t {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for $t {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+ops!(F32, u32, 6);
+ops!(F64, u64, 14);
+
+impl F32 {
+    /// Add two values as ADD UNNORMALIZED does.
+    ///
+    /// This is `+` without the final normalization: the sum keeps any leading zero digits, and its
+    /// guard digit is truncated without being shifted into the result. A carry still shifts the
+    /// sum right by one digit. The result's characteristic is never smaller than the operands', so
+    /// exponent underflow cannot occur. A zero sum, including the guard digit, is a true zero as
+    /// for `+`.
+    ///
+    /// ```
+    /// use ibmfloat::F32;
+    ///
+    /// let one = F32::from_bits(0x41100000);
+    /// let almost_one = F32::from_bits(0x40ffffff);
+    /// assert_eq!((one - almost_one).to_bits(), 0x3b100000);
+    /// assert_eq!(one.sub_unnormalized(almost_one).to_bits(), 0x41000000);
+    ///
+    /// let unnormalized = F32::from_bits(0x42010000);
+    /// assert_eq!(unnormalized.add_unnormalized(unnormalized).to_bits(), 0x42020000);
+    /// ```
+    #[inline]
+    pub const fn add_unnormalized(self, rhs: Self) -> Self {
+        let (sum, _) = arithmetic::add(self.unpack(), rhs.unpack(), 6, Masks::CLEAR, false);
+        Self::pack(sum)
+    }
+
+    /// Subtract two values as SUBTRACT UNNORMALIZED does.
+    ///
+    /// This is `-` without the final normalization; see
+    /// [`add_unnormalized()`](#method.add_unnormalized).
+    ///
+    /// ```
+    /// use ibmfloat::F32;
+    ///
+    /// let one = F32::from_bits(0x41100000);
+    /// let almost_one = F32::from_bits(0x40ffffff);
+    /// assert_eq!(one.sub_unnormalized(almost_one).to_bits(), 0x41000000);
+    /// assert_eq!(one.sub_unnormalized(one).to_bits(), 0);
+    /// ```
+    #[inline]
+    pub const fn sub_unnormalized(self, rhs: Self) -> Self {
+        self.add_unnormalized(Self(rhs.0 ^ Self::SIGN_BIT))
+    }
+
+    /// Compute `(self * a) + b`, as MULTIPLY AND ADD does.
+    ///
+    /// Unlike `*` followed by `+`, the product is not truncated before it is added to `b`. The sum
+    /// is normalized and truncated to an `F32`, with exponent underflow and zero sums producing
+    /// true zeros as for `+`.
+    ///
+    /// ```
+    /// use ibmfloat::F32;
+    ///
+    /// let a = F32::from_bits(0x40ffffff);
+    /// let b = F32::from_bits(0xc0fffffe);
+    /// assert_eq!((a * a + b).to_bits(), 0x00000000);
+    /// assert_eq!(a.mul_add(a, b).to_bits(), 0x35100000);
+    /// ```
+    #[inline]
+    pub const fn mul_add(self, a: Self, b: Self) -> Self {
+        let (sum, _) = arithmetic::mul_add(self.unpack(), a.unpack(), b.unpack(), 6, Masks::CLEAR);
+        Self::pack(sum)
+    }
+
+    /// Compute `(self * a) - b`, as MULTIPLY AND SUBTRACT does.
+    ///
+    /// Unlike `*` followed by `-`, the product is not truncated before `b` is subtracted from it.
+    /// The difference is normalized and truncated to an `F32`, with exponent underflow and zero
+    /// differences producing true zeros as for `-`.
+    ///
+    /// ```
+    /// use ibmfloat::F32;
+    ///
+    /// let a = F32::from_bits(0x40ffffff);
+    /// let b = -F32::from_bits(0xc0fffffe);
+    /// assert_eq!((a * a - b).to_bits(), 0x00000000);
+    /// assert_eq!(a.mul_sub(a, b).to_bits(), 0x35100000);
+    /// ```
+    #[inline]
+    pub const fn mul_sub(self, a: Self, b: Self) -> Self {
+        let b = Self(b.0 ^ Self::SIGN_BIT);
+        self.mul_add(a, b)
+    }
+
+    /// Take the square root of this value, as SQUARE ROOT does.
+    ///
+    /// The operand is normalized, and the root is rounded to the nearest `F32`, with halfway
+    /// cases rounded up. Zeros of either sign produce a true zero. Negative values return
+    /// `Err(Exception::SquareRoot)`, since the hardware suppresses the operation.
+    ///
+    /// ```
+    /// use ibmfloat::{Exception, F32};
+    ///
+    /// assert_eq!(F32::from_bits(0x41400000).sqrt().ok().map(F32::to_bits), Some(0x41200000));
+    /// assert_eq!(F32::from_bits(0x41200000).sqrt().ok().map(F32::to_bits), Some(0x4116a09e));
+    /// assert_eq!(F32::from_bits(0x41300000).sqrt().ok().map(F32::to_bits), Some(0x411bb67b));
+    /// assert_eq!(F32::from_bits(0xc1100000).sqrt().err(), Some(Exception::SquareRoot));
+    /// ```
+    #[inline]
+    pub const fn sqrt(self) -> Result<Self, Exception> {
+        match arithmetic::sqrt(self.unpack(), 6) {
+            (_, Some(e)) => Err(e),
+            (root, None) => Ok(Self::pack(root)),
+        }
+    }
+
+    /// Multiply two values without truncating the product, as MULTIPLY (short to long) does.
+    ///
+    /// The product of two 6-digit fractions always fits in the 14-digit fraction of an `F64`, so
+    /// the result is exact unless the characteristic overflows or underflows, which is handled as
+    /// for `*`. [`HfpContext::mul_wide()`](struct.HfpContext.html#method.mul_wide) also honors the
+    /// exponent underflow mask and reports either exception.
+    ///
+    /// ```
+    /// use ibmfloat::F32;
+    ///
+    /// let a = F32::from_bits(0x40ffffff);
+    /// assert_eq!((a * a).to_bits(), 0x40fffffe);
+    /// assert_eq!(a.mul_wide(a).to_bits(), 0x40fffffe00000100);
+    /// ```
+    #[inline]
+    pub const fn mul_wide(self, rhs: Self) -> F64 {
+        self.mul_wide_masked(rhs, Masks::CLEAR).0
+    }
+
+    /// Multiply two values as `mul_wide()` does under `masks`, returning any exception.
+    #[inline]
+    pub(crate) const fn mul_wide_masked(self, rhs: Self, masks: Masks) -> (F64, Option<Exception>) {
+        let product = arithmetic::mul_exact(self.unpack(), rhs.unpack(), 6);
+        let (product, exception) = Hfp {
+            negative: product.negative,
+            characteristic: product.characteristic,
+            fraction: product.fraction << 8,
+        }
+        .finish(masks);
+        (F64::from_bits(product.pack(14) as u64), exception)
+    }
+
+    /// Compare two values as COMPARE does, returning the condition code.
+    ///
+    /// The values are compared by subtracting them as SUBTRACT NORMALIZED would, so they compare
+    /// equal whenever the difference is zero after aligning them with one guard digit. This makes
+    /// unnormalized values compare by value, makes zeros of any sign or characteristic equal, and
+    /// makes a dirty zero with a large characteristic equal to any value small enough to be
+    /// shifted away entirely. No exceptions are possible.
+    ///
+    /// ```
+    /// use ibmfloat::{ConditionCode, F32};
+    ///
+    /// let one = F32::from_bits(0x41100000);
+    /// assert_eq!(one.compare(F32::from_bits(0x40ffffff)), ConditionCode::Cc2);
+    /// assert_eq!(F32::from_bits(0x40ffffff).compare(one), ConditionCode::Cc1);
+    ///
+    /// // An unnormalized one
+    /// assert_eq!(one.compare(F32::from_bits(0x42010000)), ConditionCode::Cc0);
+    ///
+    /// // A dirty zero shifts one beyond the guard digit
+    /// assert_eq!(F32::from_bits(0x48000000).compare(one), ConditionCode::Cc0);
+    /// ```
+    #[inline]
+    pub const fn compare(self, rhs: Self) -> ConditionCode {
+        ConditionCode::from_ordering(arithmetic::compare(self.unpack(), rhs.unpack(), 6))
+    }
+
+    /// Load this value as LOAD AND TEST does, returning it unchanged along with the condition
+    /// code.
+    ///
+    /// The condition code is `Cc0` if the fraction is zero, regardless of the sign and
+    /// characteristic, and otherwise `Cc1` for negative values and `Cc2` for positive ones. The
+    /// value is not normalized.
+    ///
+    /// ```
+    /// use ibmfloat::{ConditionCode, F32};
+    ///
+    /// let (value, cc) = F32::from_bits(0xc2010000).load_and_test();
+    /// assert_eq!(value.to_bits(), 0xc2010000);
+    /// assert_eq!(cc, ConditionCode::Cc1);
+    ///
+    /// let (_, cc) = F32::from_bits(0x80000000).load_and_test();
+    /// assert_eq!(cc, ConditionCode::Cc0);
+    /// ```
+    #[inline]
+    pub const fn load_and_test(self) -> (Self, ConditionCode) {
+        let value = self.unpack();
+        (
+            self,
+            ConditionCode::from_sign(value.negative, value.fraction),
+        )
+    }
+
+    /// Load this value with its sign inverted, as LOAD COMPLEMENT does, returning it along with
+    /// the condition code.
+    ///
+    /// The sign is inverted even for zeros, and the value is not normalized. The condition code is
+    /// set as by [`load_and_test()`](#method.load_and_test).
+    ///
+    /// ```
+    /// use ibmfloat::{ConditionCode, F32};
+    ///
+    /// let (value, cc) = F32::from_bits(0x41100000).load_complement();
+    /// assert_eq!(value.to_bits(), 0xc1100000);
+    /// assert_eq!(cc, ConditionCode::Cc1);
+    ///
+    /// let (value, cc) = F32::from_bits(0x80000000).load_complement();
+    /// assert_eq!(value.to_bits(), 0);
+    /// assert_eq!(cc, ConditionCode::Cc0);
+    /// ```
+    #[inline]
+    pub const fn load_complement(self) -> (Self, ConditionCode) {
+        Self(self.0 ^ Self::SIGN_BIT).load_and_test()
+    }
+
+    /// Load this value with its sign cleared, as LOAD POSITIVE does, returning it along with the
+    /// condition code.
+    ///
+    /// The value is not normalized. The condition code is `Cc0` if the fraction is zero and `Cc2`
+    /// otherwise.
+    ///
+    /// ```
+    /// use ibmfloat::{ConditionCode, F32};
+    ///
+    /// let (value, cc) = F32::from_bits(0xc2010000).load_positive();
+    /// assert_eq!(value.to_bits(), 0x42010000);
+    /// assert_eq!(cc, ConditionCode::Cc2);
+    /// ```
+    #[inline]
+    pub const fn load_positive(self) -> (Self, ConditionCode) {
+        Self(self.0 & !Self::SIGN_BIT).load_and_test()
+    }
+
+    /// Load this value with its sign set, as LOAD NEGATIVE does, returning it along with the
+    /// condition code.
+    ///
+    /// The sign is set even for zeros, and the value is not normalized. The condition code is
+    /// `Cc0` if the fraction is zero and `Cc1` otherwise.
+    ///
+    /// ```
+    /// use ibmfloat::{ConditionCode, F32};
+    ///
+    /// let (value, cc) = F32::from_bits(0x42010000).load_negative();
+    /// assert_eq!(value.to_bits(), 0xc2010000);
+    /// assert_eq!(cc, ConditionCode::Cc1);
+    ///
+    /// let (value, cc) = F32::from_bits(0).load_negative();
+    /// assert_eq!(value.to_bits(), 0x80000000);
+    /// assert_eq!(cc, ConditionCode::Cc0);
+    /// ```
+    #[inline]
+    pub const fn load_negative(self) -> (Self, ConditionCode) {
+        Self(self.0 | Self::SIGN_BIT).load_and_test()
+    }
+
+    /// Divide this value by two, as HALVE does.
+    ///
+    /// The fraction is shifted right one bit, keeping the shifted-out bit in a guard digit, and the
+    /// result is normalized and truncated. The result is therefore the same as multiplying by
+    /// one half with `*`: unnormalized values are normalized, and underflow and zero fractions
+    /// produce a true zero.
+    ///
+    /// The hardware leaves the condition code unchanged.
+    ///
+    /// ```
+    /// use ibmfloat::F32;
+    ///
+    /// assert_eq!(F32::from_bits(0x41300000).halve().to_bits(), 0x41180000);
+    /// assert_eq!(F32::from_bits(0x41100000).halve().to_bits(), 0x40800000);
+    /// assert_eq!(F32::from_bits(0x41300001).halve().to_bits(), 0x41180000);
+    /// assert_eq!(F32::from_bits(0x42010000).halve().to_bits(), 0x40800000);
+    /// ```
+    #[inline]
+    pub const fn halve(self) -> Self {
+        Self::pack(arithmetic::halve(self.unpack(), 6, Masks::CLEAR).0)
+    }
+}
+
+impl F64 {
+    /// Add two values as ADD UNNORMALIZED does.
+    ///
+    /// This is `+` without the final normalization: the sum keeps any leading zero digits, and its
+    /// guard digit is truncated without being shifted into the result. A carry still shifts the
+    /// sum right by one digit. The result's characteristic is never smaller than the operands', so
+    /// exponent underflow cannot occur. A zero sum, including the guard digit, is a true zero as
+    /// for `+`.
+    ///
+    /// ```
+    /// use ibmfloat::F64;
+    ///
+    /// let one = F64::from_bits(0x4110000000000000);
+    /// let almost_one = F64::from_bits(0x40ffffffffffffff);
+    /// assert_eq!((one - almost_one).to_bits(), 0x3310000000000000);
+    /// assert_eq!(one.sub_unnormalized(almost_one).to_bits(), 0x4100000000000000);
+    ///
+    /// let unnormalized = F64::from_bits(0x4201000000000000);
+    /// assert_eq!(unnormalized.add_unnormalized(unnormalized).to_bits(), 0x4202000000000000);
+    /// ```
+    #[inline]
+    pub const fn add_unnormalized(self, rhs: Self) -> Self {
+        let (sum, _) = arithmetic::add(self.unpack(), rhs.unpack(), 14, Masks::CLEAR, false);
+        Self::pack(sum)
+    }
+
+    /// Subtract two values as SUBTRACT UNNORMALIZED does.
+    ///
+    /// This is `-` without the final normalization; see
+    /// [`add_unnormalized()`](#method.add_unnormalized).
+    ///
+    /// ```
+    /// use ibmfloat::F64;
+    ///
+    /// let one = F64::from_bits(0x4110000000000000);
+    /// let almost_one = F64::from_bits(0x40ffffffffffffff);
+    /// assert_eq!(one.sub_unnormalized(almost_one).to_bits(), 0x4100000000000000);
+    /// assert_eq!(one.sub_unnormalized(one).to_bits(), 0);
+    /// ```
+    #[inline]
+    pub const fn sub_unnormalized(self, rhs: Self) -> Self {
+        self.add_unnormalized(Self(rhs.0 ^ Self::SIGN_BIT))
+    }
+
+    /// Compute `(self * a) + b`, as MULTIPLY AND ADD does.
+    ///
+    /// Unlike `*` followed by `+`, the product is not truncated before it is added to `b`. The sum
+    /// is normalized and truncated to an `F64`, with exponent underflow and zero sums producing
+    /// true zeros as for `+`.
+    ///
+    /// ```
+    /// use ibmfloat::F64;
+    ///
+    /// let a = F64::from_bits(0x40ffffffffffffff);
+    /// let b = F64::from_bits(0xc0fffffffffffffe);
+    /// assert_eq!((a * a + b).to_bits(), 0x0000000000000000);
+    /// assert_eq!(a.mul_add(a, b).to_bits(), 0x2510000000000000);
+    /// ```
+    #[inline]
+    pub const fn mul_add(self, a: Self, b: Self) -> Self {
+        let (sum, _) = arithmetic::mul_add(self.unpack(), a.unpack(), b.unpack(), 14, Masks::CLEAR);
+        Self::pack(sum)
+    }
+
+    /// Compute `(self * a) - b`, as MULTIPLY AND SUBTRACT does.
+    ///
+    /// Unlike `*` followed by `-`, the product is not truncated before `b` is subtracted from it.
+    /// The difference is normalized and truncated to an `F64`, with exponent underflow and zero
+    /// differences producing true zeros as for `-`.
+    ///
+    /// ```
+    /// use ibmfloat::F64;
+    ///
+    /// let a = F64::from_bits(0x40ffffffffffffff);
+    /// let b = -F64::from_bits(0xc0fffffffffffffe);
+    /// assert_eq!((a * a - b).to_bits(), 0x0000000000000000);
+    /// assert_eq!(a.mul_sub(a, b).to_bits(), 0x2510000000000000);
+    /// ```
+    #[inline]
+    pub const fn mul_sub(self, a: Self, b: Self) -> Self {
+        let b = Self(b.0 ^ Self::SIGN_BIT);
+        self.mul_add(a, b)
+    }
+
+    /// Take the square root of this value, as SQUARE ROOT does.
+    ///
+    /// The operand is normalized, and the root is rounded to the nearest `F64`, with halfway
+    /// cases rounded up. Zeros of either sign produce a true zero. Negative values return
+    /// `Err(Exception::SquareRoot)`, since the hardware suppresses the operation.
+    ///
+    /// ```
+    /// use ibmfloat::{Exception, F64};
+    ///
+    /// let two = F64::from_bits(0x4120000000000000);
+    /// assert_eq!(two.sqrt().ok().map(F64::to_bits), Some(0x4116a09e667f3bcd));
+    /// assert_eq!((-two).sqrt().err(), Some(Exception::SquareRoot));
+    /// ```
+    #[inline]
+    pub const fn sqrt(self) -> Result<Self, Exception> {
+        match arithmetic::sqrt(self.unpack(), 14) {
+            (_, Some(e)) => Err(e),
+            (root, None) => Ok(Self::pack(root)),
+        }
+    }
+
+    /// Multiply two values without truncating the product, as MULTIPLY (long to extended) does.
+    ///
+    /// The product of two 14-digit fractions always fits in the 28-digit fraction of an `F128`, so
+    /// the result is exact unless the characteristic overflows or underflows, which is handled as
+    /// for `*`. [`HfpContext::mul_wide()`](struct.HfpContext.html#method.mul_wide) also honors the
+    /// exponent underflow mask and reports either exception. The low-order sign and characteristic
+    /// are set as the hardware sets them.
+    ///
+    /// ```
+    /// use ibmfloat::F64;
+    ///
+    /// let a = F64::from_bits(0x4110000000000001);
+    /// assert_eq!((a * a).to_bits(), 0x4110000000000002);
+    /// assert_eq!(a.mul_wide(a).to_bits(), 0x4110000000000002_3300000000000010);
+    /// ```
+    #[inline]
+    pub const fn mul_wide(self, rhs: Self) -> F128 {
+        self.mul_wide_masked(rhs, Masks::CLEAR).0
+    }
+
+    /// Multiply two values as `mul_wide()` does under `masks`, returning any exception.
+    #[inline]
+    pub(crate) const fn mul_wide_masked(
+        self,
+        rhs: Self,
+        masks: Masks,
+    ) -> (F128, Option<Exception>) {
+        let product = arithmetic::mul_exact(self.unpack(), rhs.unpack(), 14);
+        let (product, exception) = product.finish(masks);
+
+        // Split the fraction across the high- and low-order parts
+        let high = Hfp {
+            negative: product.negative,
+            characteristic: product.characteristic,
+            fraction: product.fraction >> 56,
+        };
+        let low = product.fraction & 0x00ff_ffff_ffff_ffff;
+        let bits = F128::with_low_order_part(high.pack(14) << 64 | low);
+        (F128::from_bits(bits), exception)
+    }
+
+    /// Compare two values as COMPARE does, returning the condition code.
+    ///
+    /// The values are compared by subtracting them as SUBTRACT NORMALIZED would, so they compare
+    /// equal whenever the difference is zero after aligning them with one guard digit. This makes
+    /// unnormalized values compare by value, makes zeros of any sign or characteristic equal, and
+    /// makes a dirty zero with a large characteristic equal to any value small enough to be
+    /// shifted away entirely. No exceptions are possible.
+    ///
+    /// ```
+    /// use ibmfloat::{ConditionCode, F64};
+    ///
+    /// let one = F64::from_bits(0x4110000000000000);
+    /// assert_eq!(one.compare(F64::from_bits(0x40ffffffffffffff)), ConditionCode::Cc2);
+    /// assert_eq!(one.compare(F64::from_bits(0x4201000000000000)), ConditionCode::Cc0);
+    /// assert_eq!(F64::from_bits(0x5000000000000000).compare(one), ConditionCode::Cc0);
+    /// assert_eq!(F64::from_bits(0x4f00000000000000).compare(-one), ConditionCode::Cc2);
+    /// ```
+    #[inline]
+    pub const fn compare(self, rhs: Self) -> ConditionCode {
+        ConditionCode::from_ordering(arithmetic::compare(self.unpack(), rhs.unpack(), 14))
+    }
+
+    /// Load this value as LOAD AND TEST does, returning it unchanged along with the condition
+    /// code.
+    ///
+    /// The condition code is `Cc0` if the fraction is zero, regardless of the sign and
+    /// characteristic, and otherwise `Cc1` for negative values and `Cc2` for positive ones. The
+    /// value is not normalized.
+    ///
+    /// ```
+    /// use ibmfloat::{ConditionCode, F64};
+    ///
+    /// let (value, cc) = F64::from_bits(0xc201000000000000).load_and_test();
+    /// assert_eq!(value.to_bits(), 0xc201000000000000);
+    /// assert_eq!(cc, ConditionCode::Cc1);
+    ///
+    /// let (_, cc) = F64::from_bits(0xc800000000000000).load_and_test();
+    /// assert_eq!(cc, ConditionCode::Cc0);
+    /// ```
+    #[inline]
+    pub const fn load_and_test(self) -> (Self, ConditionCode) {
+        let value = self.unpack();
+        (
+            self,
+            ConditionCode::from_sign(value.negative, value.fraction),
+        )
+    }
+
+    /// Load this value with its sign inverted, as LOAD COMPLEMENT does, returning it along with
+    /// the condition code.
+    ///
+    /// The sign is inverted even for zeros, and the value is not normalized. The condition code is
+    /// set as by [`load_and_test()`](#method.load_and_test).
+    ///
+    /// ```
+    /// use ibmfloat::{ConditionCode, F64};
+    ///
+    /// let (value, cc) = F64::from_bits(0x4110000000000000).load_complement();
+    /// assert_eq!(value.to_bits(), 0xc110000000000000);
+    /// assert_eq!(cc, ConditionCode::Cc1);
+    ///
+    /// let (value, cc) = F64::from_bits(0x8000000000000000).load_complement();
+    /// assert_eq!(value.to_bits(), 0);
+    /// assert_eq!(cc, ConditionCode::Cc0);
+    /// ```
+    #[inline]
+    pub const fn load_complement(self) -> (Self, ConditionCode) {
+        Self(self.0 ^ Self::SIGN_BIT).load_and_test()
+    }
+
+    /// Load this value with its sign cleared, as LOAD POSITIVE does, returning it along with the
+    /// condition code.
+    ///
+    /// The value is not normalized. The condition code is `Cc0` if the fraction is zero and `Cc2`
+    /// otherwise.
+    ///
+    /// ```
+    /// use ibmfloat::{ConditionCode, F64};
+    ///
+    /// let (value, cc) = F64::from_bits(0xc201000000000000).load_positive();
+    /// assert_eq!(value.to_bits(), 0x4201000000000000);
+    /// assert_eq!(cc, ConditionCode::Cc2);
+    /// ```
+    #[inline]
+    pub const fn load_positive(self) -> (Self, ConditionCode) {
+        Self(self.0 & !Self::SIGN_BIT).load_and_test()
+    }
+
+    /// Load this value with its sign set, as LOAD NEGATIVE does, returning it along with the
+    /// condition code.
+    ///
+    /// The sign is set even for zeros, and the value is not normalized. The condition code is
+    /// `Cc0` if the fraction is zero and `Cc1` otherwise.
+    ///
+    /// ```
+    /// use ibmfloat::{ConditionCode, F64};
+    ///
+    /// let (value, cc) = F64::from_bits(0x4201000000000000).load_negative();
+    /// assert_eq!(value.to_bits(), 0xc201000000000000);
+    /// assert_eq!(cc, ConditionCode::Cc1);
+    ///
+    /// let (value, cc) = F64::from_bits(0).load_negative();
+    /// assert_eq!(value.to_bits(), 0x8000000000000000);
+    /// assert_eq!(cc, ConditionCode::Cc0);
+    /// ```
+    #[inline]
+    pub const fn load_negative(self) -> (Self, ConditionCode) {
+        Self(self.0 | Self::SIGN_BIT).load_and_test()
+    }
+
+    /// Divide this value by two, as HALVE does.
+    ///
+    /// The fraction is shifted right one bit, keeping the shifted-out bit in a guard digit, and the
+    /// result is normalized and truncated. The result is therefore the same as multiplying by
+    /// one half with `*`: unnormalized values are normalized, and underflow and zero fractions
+    /// produce a true zero.
+    ///
+    /// The hardware leaves the condition code unchanged.
+    ///
+    /// ```
+    /// use ibmfloat::F64;
+    ///
+    /// assert_eq!(F64::from_bits(0x4130000000000000).halve().to_bits(), 0x4118000000000000);
+    /// assert_eq!(F64::from_bits(0x4110000000000000).halve().to_bits(), 0x4080000000000000);
+    /// assert_eq!(F64::from_bits(0x0010000000000000).halve().to_bits(), 0);
+    /// ```
+    #[inline]
+    pub const fn halve(self) -> Self {
+        Self::pack(arithmetic::halve(self.unpack(), 14, Masks::CLEAR).0)
+    }
+
+    /// Round this value to an `F32`, as LOAD ROUNDED (long to short) does.
+    ///
+    /// One is added in the leftmost bit being dropped, and the fraction is truncated to 6 digits.
+    /// The result is not normalized, and keeps the sign and characteristic unless rounding carries
+    /// out of the fraction, in which case the fraction shifts right one digit and the
+    /// characteristic increases by one. If that overflows, the characteristic wraps around, just
+    /// like `*`;
+    /// [`HfpContext::load_rounded()`](struct.HfpContext.html#method.load_rounded) also reports it.
+    ///
+    /// The hardware leaves the condition code unchanged.
+    ///
+    /// ```
+    /// use ibmfloat::F64;
+    ///
+    /// assert_eq!(F64::from_bits(0x4112345670000000).load_rounded().to_bits(), 0x41123456);
+    /// assert_eq!(F64::from_bits(0x4112345680000000).load_rounded().to_bits(), 0x41123457);
+    /// assert_eq!(F64::from_bits(0xc1ffffff80000000).load_rounded().to_bits(), 0xc2100000);
+    /// assert_eq!(F64::from_bits(0x4200000080000000).load_rounded().to_bits(), 0x42000001);
+    /// ```
+    #[inline]
+    pub const fn load_rounded(self) -> F32 {
+        self.round().0
+    }
+
+    /// Round this value to an `F32` as `load_rounded()` does, returning any exception.
+    #[inline]
+    pub(crate) const fn round(self) -> (F32, Option<Exception>) {
+        let (rounded, exception) = arithmetic::round(self.unpack(), 6, 8);
+        (F32::from_bits(rounded.pack(6) as u32), exception)
+    }
+}
+
+impl F128 {
+    /// Round this value to an `F64`, as LOAD ROUNDED (extended to long) does.
+    ///
+    /// One is added in the leftmost bit of the low-order fraction, and the fraction is truncated to
+    /// 14 digits. The low-order sign and characteristic are ignored. The result is not normalized,
+    /// and keeps the sign and characteristic unless rounding carries out of the fraction, in which
+    /// case the fraction shifts right one digit and the characteristic increases by one. If that
+    /// overflows, the characteristic wraps around, just like `*`;
+    /// [`HfpContext::load_rounded()`](struct.HfpContext.html#method.load_rounded) also reports it.
+    ///
+    /// The hardware leaves the condition code unchanged.
+    ///
+    /// ```
+    /// use ibmfloat::F128;
+    ///
+    /// let value = F128::from_bits(0x4110000000000000_3370000000000000);
+    /// assert_eq!(value.load_rounded().to_bits(), 0x4110000000000000);
+    ///
+    /// let value = F128::from_bits(0x4110000000000000_3380000000000000);
+    /// assert_eq!(value.load_rounded().to_bits(), 0x4110000000000001);
+    /// ```
+    #[inline]
+    pub const fn load_rounded(self) -> F64 {
+        self.round().0
+    }
+
+    /// Round this value to an `F64` as `load_rounded()` does, returning any exception.
+    #[inline]
+    pub(crate) const fn round(self) -> (F64, Option<Exception>) {
+        let high = Hfp::unpack(self.0 >> 64, 14);
+        let extended = Hfp {
+            negative: high.negative,
+            characteristic: high.characteristic,
+            fraction: high.fraction << 56 | self.0 & 0x00ff_ffff_ffff_ffff,
+        };
+        let (rounded, exception) = arithmetic::round(extended, 14, 14);
+        (F64::from_bits(rounded.pack(14) as u64), exception)
+    }
+}
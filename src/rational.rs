@@ -0,0 +1,31 @@
+//! Exact conversions to `num_rational::BigRational`.
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+
+use crate::{F32, F64};
+
+/// Build a `BigRational` equal to `sign * mantissa * 2^exponent`.
+fn from_integer_decode((mantissa, exponent, sign): (u64, i16, i8)) -> BigRational {
+    let numer = BigInt::from(mantissa) * BigInt::from(sign);
+    let one = BigInt::from(1u8);
+    if exponent >= 0 {
+        BigRational::from_integer(numer << exponent as usize)
+    } else {
+        BigRational::new(numer, one << -exponent as usize)
+    }
+}
+
+impl From<F32> for BigRational {
+    /// Convert an `F32` to a `BigRational` without any loss of precision.
+    fn from(v: F32) -> Self {
+        from_integer_decode(v.integer_decode())
+    }
+}
+
+impl From<F64> for BigRational {
+    /// Convert an `F64` to a `BigRational` without any loss of precision.
+    fn from(v: F64) -> Self {
+        from_integer_decode(v.integer_decode())
+    }
+}
@@ -0,0 +1,184 @@
+use crate::convert::{split32, split64};
+use crate::{Exception, F32, F64};
+
+macro_rules! scale {
+    ($U:ty, $split:ident, $scale:ident, $frexp:ident) => {
+        /// Add `n` to the characteristic of an IBM float represented as uint U, leaving the sign
+        /// and fraction untouched. Zeros are returned unchanged.
+        const fn $scale(ibm: $U, n: i32) -> Result<$U, Exception> {
+            let exponent_shift = <$U>::BITS - 8;
+            let (sign, characteristic, fraction) = $split(ibm);
+            if fraction == 0 {
+                return Ok(ibm);
+            }
+
+            let characteristic = (characteristic as i32).saturating_add(n);
+            if characteristic > 0x7f {
+                Err(Exception::ExponentOverflow)
+            } else if characteristic < 0 {
+                Err(Exception::ExponentUnderflow)
+            } else {
+                Ok(sign | (characteristic as $U) << exponent_shift | fraction)
+            }
+        }
+
+        /// Normalize an IBM float represented as uint U to a characteristic of 64, returning the
+        /// normalized value and the power of 16 it must be scaled by to recover the original.
+        /// Zeros are returned as true zeros with the original sign.
+        const fn $frexp(ibm: $U) -> ($U, i32) {
+            let exponent_shift = <$U>::BITS - 8;
+            let (sign, characteristic, fraction) = $split(ibm);
+            if fraction == 0 {
+                return (sign, 0);
+            }
+
+            // Shift out leading zero hex digits
+            let digits = (fraction.leading_zeros() - 8) / 4;
+            let fraction = fraction << (digits * 4);
+            (
+                sign | 64 << exponent_shift | fraction,
+                characteristic as i32 - 64 - digits as i32,
+            )
+        }
+    };
+}
+scale!(u32, split32, scale32, frexp32);
+scale!(u64, split64, scale64, frexp64);
+
+impl F32 {
+    /// Multiply this value by `16^n` by adjusting its characteristic.
+    ///
+    /// The fraction is left untouched, so the result is exact and preserves any unnormalized
+    /// encoding. Zeros are returned unchanged. If the new characteristic is out of range, an
+    /// exponent overflow or underflow is reported instead; see [`ldexp16`](#method.ldexp16) for a
+    /// variant which normalizes first.
+    ///
+    /// ```
+    /// use ibmfloat::{Exception, F32};
+    ///
+    /// let one = F32::from_bits(0x41100000);
+    /// assert_eq!(one.scale_hex(2).unwrap().to_bits(), 0x43100000);
+    /// assert_eq!(one.scale_hex(-3).unwrap().to_bits(), 0x3e100000);
+    /// assert_eq!(one.scale_hex(63).err(), Some(Exception::ExponentOverflow));
+    /// assert_eq!(one.scale_hex(-66).err(), Some(Exception::ExponentUnderflow));
+    /// ```
+    #[inline]
+    pub const fn scale_hex(self, n: i32) -> Result<Self, Exception> {
+        match scale32(self.0, n) {
+            Ok(bits) => Ok(Self(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Split this value into a normalized fraction and a power of 16.
+    ///
+    /// The returned fraction has a characteristic of 64, so its magnitude is in `[1/16, 1)`, and
+    /// `fraction * 16^exponent` is exactly equal to `self`. Zeros return a true zero of the same
+    /// sign and an exponent of `0`.
+    ///
+    /// ```
+    /// use ibmfloat::F32;
+    ///
+    /// // -118.625 is -0x0.76a * 16^2
+    /// let (fraction, exponent) = F32::from_bits(0xc276a000).frexp16();
+    /// assert_eq!((fraction.to_bits(), exponent), (0xc076a000, 2));
+    ///
+    /// // Unnormalized values are normalized
+    /// let (fraction, exponent) = F32::from_bits(0x46000001).frexp16();
+    /// assert_eq!((fraction.to_bits(), exponent), (0x40100000, 1));
+    /// ```
+    #[inline]
+    pub const fn frexp16(self) -> (Self, i32) {
+        let (bits, exponent) = frexp32(self.0);
+        (Self(bits), exponent)
+    }
+
+    /// Multiply this value by `16^exp`, normalizing it first.
+    ///
+    /// This is the inverse of [`frexp16`](#method.frexp16). The result is exact and normalized;
+    /// zeros become true zeros of the same sign. If the normalized result's characteristic is out
+    /// of range, an exponent overflow or underflow is reported instead.
+    ///
+    /// ```
+    /// use ibmfloat::{Exception, F32};
+    ///
+    /// let value = F32::from_bits(0x46000001);
+    /// assert_eq!(value.ldexp16(-1).unwrap().to_bits(), 0x40100000);
+    ///
+    /// // scale_hex() would overflow, since it keeps the leading zero digits
+    /// let huge = F32::from_bits(0x7e000100);
+    /// assert_eq!(huge.scale_hex(2).err(), Some(Exception::ExponentOverflow));
+    /// assert_eq!(huge.ldexp16(2).unwrap().to_bits(), 0x7d100000);
+    ///
+    /// let (fraction, exponent) = value.frexp16();
+    /// assert_eq!(fraction.ldexp16(exponent).unwrap().to_bits(), 0x41100000);
+    /// ```
+    #[inline]
+    pub const fn ldexp16(self, exp: i32) -> Result<Self, Exception> {
+        let (bits, exponent) = frexp32(self.0);
+        Self(bits).scale_hex(exponent.saturating_add(exp))
+    }
+}
+
+impl F64 {
+    /// Multiply this value by `16^n` by adjusting its characteristic.
+    ///
+    /// The fraction is left untouched, so the result is exact and preserves any unnormalized
+    /// encoding. Zeros are returned unchanged. If the new characteristic is out of range, an
+    /// exponent overflow or underflow is reported instead; see [`ldexp16`](#method.ldexp16) for a
+    /// variant which normalizes first.
+    ///
+    /// ```
+    /// use ibmfloat::{Exception, F64};
+    ///
+    /// let one = F64::from_bits(0x4110000000000000);
+    /// assert_eq!(one.scale_hex(2).unwrap().to_bits(), 0x4310000000000000);
+    /// assert_eq!(one.scale_hex(-3).unwrap().to_bits(), 0x3e10000000000000);
+    /// assert_eq!(one.scale_hex(63).err(), Some(Exception::ExponentOverflow));
+    /// assert_eq!(one.scale_hex(-66).err(), Some(Exception::ExponentUnderflow));
+    /// ```
+    #[inline]
+    pub const fn scale_hex(self, n: i32) -> Result<Self, Exception> {
+        match scale64(self.0, n) {
+            Ok(bits) => Ok(Self(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Split this value into a normalized fraction and a power of 16.
+    ///
+    /// The returned fraction has a characteristic of 64, so its magnitude is in `[1/16, 1)`, and
+    /// `fraction * 16^exponent` is exactly equal to `self`. Zeros return a true zero of the same
+    /// sign and an exponent of `0`.
+    ///
+    /// ```
+    /// use ibmfloat::F64;
+    ///
+    /// let (fraction, exponent) = F64::from_bits(0x4800000000000001).frexp16();
+    /// assert_eq!((fraction.to_bits(), exponent), (0x4010000000000000, -5));
+    /// ```
+    #[inline]
+    pub const fn frexp16(self) -> (Self, i32) {
+        let (bits, exponent) = frexp64(self.0);
+        (Self(bits), exponent)
+    }
+
+    /// Multiply this value by `16^exp`, normalizing it first.
+    ///
+    /// This is the inverse of [`frexp16`](#method.frexp16). The result is exact and normalized;
+    /// zeros become true zeros of the same sign. If the normalized result's characteristic is out
+    /// of range, an exponent overflow or underflow is reported instead.
+    ///
+    /// ```
+    /// use ibmfloat::{Exception, F64};
+    ///
+    /// let value = F64::from_bits(0x4800000000000001);
+    /// assert_eq!(value.ldexp16(5).unwrap().to_bits(), 0x4010000000000000);
+    /// assert_eq!(value.ldexp16(-65).err(), Some(Exception::ExponentUnderflow));
+    /// ```
+    #[inline]
+    pub const fn ldexp16(self, exp: i32) -> Result<Self, Exception> {
+        let (bits, exponent) = frexp64(self.0);
+        Self(bits).scale_hex(exponent.saturating_add(exp))
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(not(feature = "std"))]
+use core::ops::Neg;
+#[cfg(feature = "std")]
+use std::ops::Neg;
+
+use crate::{F32, F64};
+
+// IBM floats are sign-magnitude, so everything here is a bit operation on the sign bit. Zeros,
+// including "dirty" zeros with a nonzero characteristic, keep their characteristic and fraction.
+
+impl F32 {
+    pub(crate) const SIGN_BIT: u32 = 0x8000_0000;
+
+    /// Returns `true` if this value has a positive sign, including `+0` and positive dirty zeros.
+    ///
+    /// ```
+    /// assert!(ibmfloat::F32::from_bits(0x41100000).is_sign_positive());
+    /// assert!(ibmfloat::F32::from_bits(0x00000000).is_sign_positive());
+    /// assert!(!ibmfloat::F32::from_bits(0x80000000).is_sign_positive());
+    /// ```
+    #[inline]
+    pub const fn is_sign_positive(self) -> bool {
+        self.0 & Self::SIGN_BIT == 0
+    }
+
+    /// Returns `true` if this value has a negative sign, including `-0` and negative dirty zeros.
+    ///
+    /// ```
+    /// assert!(ibmfloat::F32::from_bits(0xc1100000).is_sign_negative());
+    /// assert!(ibmfloat::F32::from_bits(0x80000000).is_sign_negative());
+    /// assert!(!ibmfloat::F32::from_bits(0x00000000).is_sign_negative());
+    /// ```
+    #[inline]
+    pub const fn is_sign_negative(self) -> bool {
+        !self.is_sign_positive()
+    }
+
+    /// Computes the absolute value of `self` by clearing the sign bit.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F32::from_bits(0xc276a000);
+    ///
+    /// assert_eq!(foreign_float.abs().to_bits(), 0x4276a000);
+    /// assert_eq!(f64::from(foreign_float.abs()), 118.625);
+    /// ```
+    #[inline]
+    pub const fn abs(self) -> Self {
+        Self(self.0 & !Self::SIGN_BIT)
+    }
+
+    /// Returns a value with the magnitude of `self` and the sign of `sign`.
+    ///
+    /// ```
+    /// let magnitude = ibmfloat::F32::from_bits(0x4276a000);
+    /// let sign = ibmfloat::F32::from_bits(0x80000000);
+    ///
+    /// assert_eq!(magnitude.copysign(sign).to_bits(), 0xc276a000);
+    /// ```
+    #[inline]
+    pub const fn copysign(self, sign: Self) -> Self {
+        Self(self.0 & !Self::SIGN_BIT | sign.0 & Self::SIGN_BIT)
+    }
+
+    /// Returns a number that represents the sign of `self`.
+    ///
+    /// * `1.0` if the sign is positive, including `+0` and positive dirty zeros
+    /// * `-1.0` if the sign is negative, including `-0` and negative dirty zeros
+    ///
+    /// This matches the behavior of `f32::signum()` for IEEE zeros.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F32::from_bits(0xc276a000);
+    /// assert_eq!(f32::from(foreign_float.signum()), -1.0);
+    ///
+    /// let dirty_zero = ibmfloat::F32::from_bits(0x42000000);
+    /// assert_eq!(f32::from(dirty_zero.signum()), 1.0);
+    /// ```
+    #[inline]
+    pub const fn signum(self) -> Self {
+        Self(0x4110_0000 | self.0 & Self::SIGN_BIT)
+    }
+}
+
+impl F64 {
+    pub(crate) const SIGN_BIT: u64 = 0x8000_0000_0000_0000;
+
+    /// Returns `true` if this value has a positive sign, including `+0` and positive dirty zeros.
+    ///
+    /// ```
+    /// assert!(ibmfloat::F64::from_bits(0x4110000000000000).is_sign_positive());
+    /// assert!(ibmfloat::F64::from_bits(0x0000000000000000).is_sign_positive());
+    /// assert!(!ibmfloat::F64::from_bits(0x8000000000000000).is_sign_positive());
+    /// ```
+    #[inline]
+    pub const fn is_sign_positive(self) -> bool {
+        self.0 & Self::SIGN_BIT == 0
+    }
+
+    /// Returns `true` if this value has a negative sign, including `-0` and negative dirty zeros.
+    ///
+    /// ```
+    /// assert!(ibmfloat::F64::from_bits(0xc110000000000000).is_sign_negative());
+    /// assert!(ibmfloat::F64::from_bits(0x8000000000000000).is_sign_negative());
+    /// assert!(!ibmfloat::F64::from_bits(0x0000000000000000).is_sign_negative());
+    /// ```
+    #[inline]
+    pub const fn is_sign_negative(self) -> bool {
+        !self.is_sign_positive()
+    }
+
+    /// Computes the absolute value of `self` by clearing the sign bit.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F64::from_bits(0xc110000000000000);
+    ///
+    /// assert_eq!(foreign_float.abs().to_bits(), 0x4110000000000000);
+    /// assert_eq!(f64::from(foreign_float.abs()), 1.0);
+    /// ```
+    #[inline]
+    pub const fn abs(self) -> Self {
+        Self(self.0 & !Self::SIGN_BIT)
+    }
+
+    /// Returns a value with the magnitude of `self` and the sign of `sign`.
+    ///
+    /// ```
+    /// let magnitude = ibmfloat::F64::from_bits(0x4110000000000000);
+    /// let sign = ibmfloat::F64::from_bits(0x8000000000000000);
+    ///
+    /// assert_eq!(magnitude.copysign(sign).to_bits(), 0xc110000000000000);
+    /// ```
+    #[inline]
+    pub const fn copysign(self, sign: Self) -> Self {
+        Self(self.0 & !Self::SIGN_BIT | sign.0 & Self::SIGN_BIT)
+    }
+
+    /// Returns a number that represents the sign of `self`.
+    ///
+    /// * `1.0` if the sign is positive, including `+0` and positive dirty zeros
+    /// * `-1.0` if the sign is negative, including `-0` and negative dirty zeros
+    ///
+    /// This matches the behavior of `f64::signum()` for IEEE zeros.
+    ///
+    /// ```
+    /// let foreign_float = ibmfloat::F64::from_bits(0xc276a00000000000);
+    /// assert_eq!(f64::from(foreign_float.signum()), -1.0);
+    ///
+    /// let dirty_zero = ibmfloat::F64::from_bits(0x4200000000000000);
+    /// assert_eq!(f64::from(dirty_zero.signum()), 1.0);
+    /// ```
+    #[inline]
+    pub const fn signum(self) -> Self {
+        Self(0x4110_0000_0000_0000 | self.0 & Self::SIGN_BIT)
+    }
+}
+
+impl Neg for F32 {
+    type Output = Self;
+
+    /// Negate this value by flipping the sign bit. Zeros are negated too, matching IEEE-754.
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0 ^ Self::SIGN_BIT)
+    }
+}
+
+impl Neg for F64 {
+    type Output = Self;
+
+    /// Negate this value by flipping the sign bit. Zeros are negated too, matching IEEE-754.
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0 ^ Self::SIGN_BIT)
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{F32, F64};
+
+// IBM floats are sign-magnitude, and a normalized fraction sorts the same way as the value it
+// represents, so the usual trick for IEEE-754 applies: set the sign bit of positive values, and
+// invert every bit of negative values. This is a bijection, so equal keys mean equal encodings.
+
+impl F32 {
+    /// Return an unsigned integer whose order matches the numeric order of normalized values.
+    ///
+    /// Keys are suitable for radix sorting. For normalized values and true zeros, the order of the
+    /// keys is the same as [`total_cmp()`](#method.total_cmp): numeric order, with `-0` before
+    /// `+0`. Unnormalized values and dirty zeros are keyed by their encoding, and do not sort
+    /// numerically.
+    ///
+    /// ```
+    /// use ibmfloat::F32;
+    ///
+    /// let minus_one = F32::from_bits(0xc1100000);
+    /// let zero = F32::from_bits(0x00000000);
+    /// let one = F32::from_bits(0x41100000);
+    /// let two = F32::from_bits(0x41200000);
+    ///
+    /// assert!(minus_one.sort_key() < zero.sort_key());
+    /// assert!(zero.sort_key() < one.sort_key());
+    /// assert!(one.sort_key() < two.sort_key());
+    /// ```
+    #[inline]
+    pub const fn sort_key(self) -> u32 {
+        if self.is_sign_positive() {
+            self.0 | 0x8000_0000
+        } else {
+            !self.0
+        }
+    }
+
+    /// Sort a slice of values in place using [`sort_key()`](#method.sort_key).
+    ///
+    /// This avoids decoding values during every comparison. The resulting order is numeric for
+    /// normalized values and true zeros; see `sort_key()` for details.
+    ///
+    /// ```
+    /// use ibmfloat::F32;
+    ///
+    /// let mut values: Vec<_> = [0x41200000, 0xc1100000, 0x00000000, 0x41100000]
+    ///     .iter()
+    ///     .map(|&bits| F32::from_bits(bits))
+    ///     .collect();
+    /// F32::sort_slice(&mut values);
+    ///
+    /// let sorted: Vec<u32> = values.iter().map(|v| v.to_bits()).collect();
+    /// assert_eq!(sorted, [0xc1100000, 0x00000000, 0x41100000, 0x41200000]);
+    /// ```
+    pub fn sort_slice(values: &mut [F32]) {
+        // Equal keys are identical encodings, so stability doesn't matter
+        values.sort_unstable_by_key(|v| v.sort_key())
+    }
+}
+
+impl F64 {
+    /// Return an unsigned integer whose order matches the numeric order of normalized values.
+    ///
+    /// Keys are suitable for radix sorting. For normalized values and true zeros, the order of the
+    /// keys is the same as [`total_cmp()`](#method.total_cmp): numeric order, with `-0` before
+    /// `+0`. Unnormalized values and dirty zeros are keyed by their encoding, and do not sort
+    /// numerically.
+    ///
+    /// ```
+    /// use ibmfloat::F64;
+    ///
+    /// let minus_one = F64::from_bits(0xc110000000000000);
+    /// let one = F64::from_bits(0x4110000000000000);
+    /// let next = F64::from_bits(0x4110000000000001);
+    ///
+    /// assert!(minus_one.sort_key() < one.sort_key());
+    /// assert!(one.sort_key() < next.sort_key());
+    /// ```
+    #[inline]
+    pub const fn sort_key(self) -> u64 {
+        if self.is_sign_positive() {
+            self.0 | 0x8000_0000_0000_0000
+        } else {
+            !self.0
+        }
+    }
+
+    /// Sort a slice of values in place using [`sort_key()`](#method.sort_key).
+    ///
+    /// This avoids decoding values during every comparison. The resulting order is numeric for
+    /// normalized values and true zeros; see `sort_key()` for details.
+    ///
+    /// ```
+    /// use ibmfloat::F64;
+    ///
+    /// let mut values = [
+    ///     F64::from_bits(0x4110000000000001),
+    ///     F64::from_bits(0xc110000000000000),
+    ///     F64::from_bits(0x4110000000000000),
+    /// ];
+    /// F64::sort_slice(&mut values);
+    /// assert!(values[0] < values[1] && values[1] < values[2]);
+    /// ```
+    pub fn sort_slice(values: &mut [F64]) {
+        // Equal keys are identical encodings, so stability doesn't matter
+        values.sort_unstable_by_key(|v| v.sort_key())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::tests::{f128s, f32s, f64s, widen_f32, widen_f64};
 use crate::*;
 
 #[test]
@@ -32,260 +33,489 @@ fn test_display() {
     }
 }
 
-fn f32s() -> impl Iterator<Item = F32> {
-    [
-        0x00000000, 0x80000000, 0x00000001, 0x80000001, 0x3f000000, 0xbf000000, 0x7f000000,
-        0xff000000, 0x1b100000, 0x9b100000, 0x1b200000, 0x9b200000, 0x1b400000, 0x9b400000,
-        0x1b400001, 0x9b400001, 0x1b800000, 0x9b800000, 0x1bbfffff, 0x9bbfffff, 0x1bc00000,
-        0x9bc00000, 0x1da7bfff, 0x9da7bfff, 0x1da7c000, 0x9da7c000, 0x1da84000, 0x9da84000,
-        0x1da84001, 0x9da84001, 0x1da8bfff, 0x9da8bfff, 0x1da8c000, 0x9da8c000, 0x1da94000,
-        0x9da94000, 0x1da94001, 0x9da94001, 0x1da9bfff, 0x9da9bfff, 0x1da9c000, 0x9da9c000,
-        0x1daa4000, 0x9daa4000, 0x1daa4001, 0x9daa4001, 0x1fffffff, 0x9fffffff, 0x20fffff4,
-        0xa0fffff4, 0x20fffff5, 0xa0fffff5, 0x20fffff6, 0xa0fffff6, 0x20fffff7, 0xa0fffff7,
-        0x20fffff8, 0xa0fffff8, 0x20fffff9, 0xa0fffff9, 0x20fffffa, 0xa0fffffa, 0x20fffffb,
-        0xa0fffffb, 0x20fffffc, 0xa0fffffc, 0x20fffffd, 0xa0fffffd, 0x20fffffe, 0xa0fffffe,
-        0x20ffffff, 0xa0ffffff, 0x21100000, 0xa1100000, 0x21200000, 0xa1200000, 0x213fffff,
-        0xa13fffff, 0x21400000, 0xa1400000, 0x40800000, 0xc0800000, 0x46000001, 0xc6000001,
-        0x45000010, 0xc5000010, 0x44000100, 0xc4000100, 0x43001000, 0xc3001000, 0x42010000,
-        0xc2010000, 0x41100000, 0xc1100000, 0x41200000, 0xc1200000, 0x41300000, 0xc1300000,
-        0x41400000, 0xc1400000, 0x41800000, 0xc1800000, 0x48000001, 0xc8000001, 0x48000002,
-        0xc8000002, 0x48000004, 0xc8000004, 0x48000008, 0xc8000008, 0x48000010, 0xc8000010,
-        0x48000020, 0xc8000020, 0x48000040, 0xc8000040, 0x48000080, 0xc8000080, 0x48000100,
-        0xc8000100, 0x48000200, 0xc8000200, 0x48000400, 0xc8000400, 0x48000800, 0xc8000800,
-        0x48001000, 0xc8001000, 0x48002000, 0xc8002000, 0x48004000, 0xc8004000, 0x48008000,
-        0xc8008000, 0x48010000, 0xc8010000, 0x48020000, 0xc8020000, 0x48040000, 0xc8040000,
-        0x48080000, 0xc8080000, 0x48100000, 0xc8100000, 0x48200000, 0xc8200000, 0x48400000,
-        0xc8400000, 0x48800000, 0xc8800000, 0x60ffffff, 0xe0ffffff, 0x61100000, 0xe1100000,
-        0x61200000, 0xe1200000, 0x61400000, 0xe1400000, 0x62100000, 0xe2100000, 0x7fffffff,
-        0xffffffff, 0xc276a000, 0x4276a000,
-    ]
-    .iter()
-    .cloned()
-    .map(F32::from_bits)
+#[test]
+fn test_const_conversions() {
+    for value in f32s() {
+        assert_eq!(value.to_f32().to_bits(), f32::from(value).to_bits());
+        assert_eq!(value.to_f64().to_bits(), f64::from(value).to_bits());
+    }
+    for value in f64s() {
+        assert_eq!(value.to_f32().to_bits(), f32::from(value).to_bits());
+        assert_eq!(value.to_f64().to_bits(), f64::from(value).to_bits());
+    }
+
+    const VALUES: [f64; 2] = [
+        F32::from_bits(0xc276a000).to_f64(),
+        F64::from_bits(0x4110000000000000).to_f64(),
+    ];
+    assert_eq!(VALUES, [-118.625, 1.0]);
+}
+
+#[test]
+fn test_integer_decode() {
+    for value in f32s() {
+        let (mantissa, exponent, sign) = value.integer_decode();
+        let reference = f64::from(sign) * mantissa as f64 * 2f64.powi(i32::from(exponent));
+        assert_eq!(reference.to_bits(), f64::from(value).to_bits());
+
+        // Widening to F64 must describe the same value
+        assert_eq!(
+            widen_f32(value).integer_decode(),
+            (mantissa << 32, exponent - 32, sign)
+        );
+    }
 }
 
-fn f64s() -> impl Iterator<Item = F64> {
-    [
-        0x0000000000000001,
-        0x8000000000000001,
-        0x0000000000000002,
-        0x8000000000000002,
-        0x0000000000000003,
-        0x8000000000000003,
-        0x400ffffffffffffe,
-        0xc00ffffffffffffe,
-        0x400fffffffffffff,
-        0xc00fffffffffffff,
-        0x4010000000000000,
-        0xc010000000000000,
-        0x4010000000000001,
-        0xc010000000000001,
-        0x4010000000000002,
-        0xc010000000000002,
-        0x401ffffffffffffe,
-        0xc01ffffffffffffe,
-        0x401fffffffffffff,
-        0xc01fffffffffffff,
-        0x4020000000000000,
-        0xc020000000000000,
-        0x4020000000000001,
-        0xc020000000000001,
-        0x4020000000000002,
-        0xc020000000000002,
-        0x4020000000000003,
-        0xc020000000000003,
-        0x403ffffffffffffd,
-        0xc03ffffffffffffd,
-        0x403ffffffffffffe,
-        0xc03ffffffffffffe,
-        0x403fffffffffffff,
-        0xc03fffffffffffff,
-        0x4040000000000002,
-        0xc040000000000002,
-        0x4040000000000003,
-        0xc040000000000003,
-        0x4040000000000005,
-        0xc040000000000005,
-        0x4040000000000006,
-        0xc040000000000006,
-        0x407ffffffffffffa,
-        0xc07ffffffffffffa,
-        0x407ffffffffffffb,
-        0xc07ffffffffffffb,
-        0x407ffffffffffffd,
-        0xc07ffffffffffffd,
-        0x407ffffffffffffe,
-        0xc07ffffffffffffe,
-        0x4080000000000004,
-        0xc080000000000004,
-        0x4080000000000005,
-        0xc080000000000005,
-        0x408000000000000b,
-        0xc08000000000000b,
-        0x408000000000000c,
-        0xc08000000000000c,
-        0x40fffffffffffff4,
-        0xc0fffffffffffff4,
-        0x40fffffffffffff5,
-        0xc0fffffffffffff5,
-        0x40fffffffffffffb,
-        0xc0fffffffffffffb,
-        0x40fffffffffffffc,
-        0xc0fffffffffffffc,
-        0x4110000000000000,
-        0xc110000000000000,
-        0x4110000000000001,
-        0xc110000000000001,
-        0x4110000000000002,
-        0xc110000000000002,
-        0x411fffffffffffff,
-        0xc11fffffffffffff,
-        0x4120000000000000,
-        0xc120000000000000,
-        0x4120000000000001,
-        0xc120000000000001,
-        0x4120000000000002,
-        0xc120000000000002,
-        0x4800000000000001,
-        0xc800000000000001,
-        0x4800000000000002,
-        0xc800000000000002,
-        0x4800000000000004,
-        0xc800000000000004,
-        0x4800000000000008,
-        0xc800000000000008,
-        0x4800000000000010,
-        0xc800000000000010,
-        0x4800000000000020,
-        0xc800000000000020,
-        0x4800000000000040,
-        0xc800000000000040,
-        0x4800000000000080,
-        0xc800000000000080,
-        0x4800000000000100,
-        0xc800000000000100,
-        0x4800000000000200,
-        0xc800000000000200,
-        0x4800000000000400,
-        0xc800000000000400,
-        0x4800000000000800,
-        0xc800000000000800,
-        0x4800000000001000,
-        0xc800000000001000,
-        0x4800000000002000,
-        0xc800000000002000,
-        0x4800000000004000,
-        0xc800000000004000,
-        0x4800000000008000,
-        0xc800000000008000,
-        0x4800000000010000,
-        0xc800000000010000,
-        0x4800000000020000,
-        0xc800000000020000,
-        0x4800000000040000,
-        0xc800000000040000,
-        0x4800000000080000,
-        0xc800000000080000,
-        0x4800000000100000,
-        0xc800000000100000,
-        0x4800000000200000,
-        0xc800000000200000,
-        0x4800000000400000,
-        0xc800000000400000,
-        0x4800000000800000,
-        0xc800000000800000,
-        0x4800000001000000,
-        0xc800000001000000,
-        0x4800000002000000,
-        0xc800000002000000,
-        0x4800000004000000,
-        0xc800000004000000,
-        0x4800000008000000,
-        0xc800000008000000,
-        0x4800000010000000,
-        0xc800000010000000,
-        0x4800000020000000,
-        0xc800000020000000,
-        0x4800000040000000,
-        0xc800000040000000,
-        0x4800000080000000,
-        0xc800000080000000,
-        0x4800000100000000,
-        0xc800000100000000,
-        0x4800000200000000,
-        0xc800000200000000,
-        0x4800000400000000,
-        0xc800000400000000,
-        0x4800000800000000,
-        0xc800000800000000,
-        0x4800001000000000,
-        0xc800001000000000,
-        0x4800002000000000,
-        0xc800002000000000,
-        0x4800004000000000,
-        0xc800004000000000,
-        0x4800008000000000,
-        0xc800008000000000,
-        0x4800010000000000,
-        0xc800010000000000,
-        0x4800020000000000,
-        0xc800020000000000,
-        0x4800040000000000,
-        0xc800040000000000,
-        0x4800080000000000,
-        0xc800080000000000,
-        0x4800100000000000,
-        0xc800100000000000,
-        0x4800200000000000,
-        0xc800200000000000,
-        0x4800400000000000,
-        0xc800400000000000,
-        0x4800800000000000,
-        0xc800800000000000,
-        0x4801000000000000,
-        0xc801000000000000,
-        0x4802000000000000,
-        0xc802000000000000,
-        0x4804000000000000,
-        0xc804000000000000,
-        0x4808000000000000,
-        0xc808000000000000,
-        0x4810000000000000,
-        0xc810000000000000,
-        0x4820000000000000,
-        0xc820000000000000,
-        0x4840000000000000,
-        0xc840000000000000,
-        0x4880000000000000,
-        0xc880000000000000,
-        0x567faef3ff3dc282,
-        0xd67faef3ff3dc282,
-        0x7ffffffffffffff4,
-        0xfffffffffffffff4,
-        0x7ffffffffffffff5,
-        0xfffffffffffffff5,
-        0x7ffffffffffffffb,
-        0xfffffffffffffffb,
-        0x7ffffffffffffffc,
-        0xfffffffffffffffc,
-        0x7ffffffffffffffd,
-        0xfffffffffffffffd,
-        0x7ffffffffffffffe,
-        0xfffffffffffffffe,
-        0x7fffffffffffffff,
-        0xffffffffffffffff,
-        0x0000000000000000,
-        0x8000000000000000,
-        0x0000000100000000,
-        0x8000000100000000,
-        0x00ffffff00000000,
-        0x80ffffff00000000,
-        0x4110000000000000,
-        0xc110000000000000,
-        0x7fffffff00000000,
-        0xffffffff00000000,
-    ]
-    .iter()
-    .cloned()
-    .map(F64::from_bits)
+#[test]
+fn test_scale() {
+    for value in f32s() {
+        let reference = f64::from(value);
+
+        let (fraction, exponent) = value.frexp16();
+        assert_eq!(f64::from(fraction) * 16f64.powi(exponent), reference);
+        assert_eq!(
+            fraction.to_bits() & 0x7f000000 == 0x40000000,
+            reference != 0.0
+        );
+
+        for n in -3..=3 {
+            let expected = reference * 16f64.powi(n);
+            if let Ok(scaled) = value.scale_hex(n) {
+                assert_eq!(f64::from(scaled), expected);
+            }
+            if let Ok(scaled) = value.ldexp16(n) {
+                assert_eq!(f64::from(scaled), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sign() {
+    for value in f32s() {
+        let reference = f64::from(value);
+        assert_eq!(f64::from(-value).to_bits(), (-reference).to_bits());
+        assert_eq!(f64::from(value.abs()).to_bits(), reference.abs().to_bits());
+        assert_eq!(f64::from(value.signum()), reference.signum());
+        assert_eq!(value.is_sign_positive(), reference.is_sign_positive());
+    }
+    for value in f64s() {
+        let reference = f64::from(value);
+        assert_eq!(f64::from(-value).to_bits(), (-reference).to_bits());
+        assert_eq!(f64::from(value.abs()).to_bits(), reference.abs().to_bits());
+        assert_eq!(f64::from(value.signum()), reference.signum());
+        assert_eq!(value.is_sign_negative(), reference.is_sign_negative());
+    }
+}
+
+#[test]
+fn test_byte_order() {
+    let orders = [
+        ByteOrder::Abcd,
+        ByteOrder::Dcba,
+        ByteOrder::Badc,
+        ByteOrder::Cdab,
+    ];
+    for value in f32s() {
+        for &order in &orders {
+            let bytes = value.to_bytes(order);
+            assert_eq!(F32::from_bytes(bytes, order).to_bits(), value.to_bits());
+        }
+    }
+    for value in f64s() {
+        for &order in &orders {
+            let bytes = value.to_bytes(order);
+            assert_eq!(F64::from_bytes(bytes, order).to_bits(), value.to_bits());
+        }
+    }
+}
+
+#[test]
+fn test_unaligned() {
+    for value in f32s() {
+        let stored = F32Be::new(value);
+        assert_eq!(stored.to_be_bytes(), value.to_be_bytes());
+        assert_eq!(F32::from(stored).to_bits(), value.to_bits());
+    }
+    for value in f64s() {
+        let stored = F64Be::from(value);
+        assert_eq!(stored.to_be_bytes(), value.to_be_bytes());
+        assert_eq!(stored.get().to_bits(), value.to_bits());
+    }
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn test_unaligned_slices() {
+    let mut buffer: Vec<u8> = f64s().flat_map(|v| v.to_be_bytes().to_vec()).collect();
+    buffer.push(0xff);
+
+    // Deliberately misalign the view
+    let (values, rest) = F32Be::slice_from_bytes(&buffer[1..]);
+    assert_eq!(values.len(), (buffer.len() - 1) / 4);
+    assert_eq!(rest.len(), (buffer.len() - 1) % 4);
+    for (value, chunk) in values.iter().zip(buffer[1..].chunks_exact(4)) {
+        assert_eq!(value.to_be_bytes(), chunk);
+    }
+    assert_eq!(
+        F32Be::slice_as_bytes(values),
+        &buffer[1..buffer.len() - rest.len()]
+    );
+
+    let (values, rest) = F64Be::slice_from_bytes_mut(&mut buffer);
+    assert_eq!(rest, &[0xff]);
+    for (value, expected) in values.iter_mut().zip(f64s()) {
+        assert_eq!(value.get().to_bits(), expected.to_bits());
+        *value = F64Be::new(-expected);
+    }
+    for (chunk, expected) in buffer.chunks_exact(8).zip(f64s()) {
+        assert_eq!(chunk, &(-expected).to_be_bytes());
+    }
+}
+
+#[test]
+fn test_bits() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let mut distinct: Vec<_> = f64s().map(F64::to_bits).collect();
+    distinct.sort_unstable();
+    distinct.dedup();
+
+    let hashed: HashSet<_> = f64s().chain(f64s()).map(Bits).collect();
+    let ordered: BTreeSet<_> = f64s().chain(f64s()).map(Bits).collect();
+    assert_eq!(hashed.len(), distinct.len());
+    assert!(ordered.iter().map(|v| v.0.to_bits()).eq(distinct));
+
+    // Every zero is numerically equal but has a distinct encoding
+    let zeros = [0x00000000, 0x80000000, 0x42000000, 0xc2000000];
+    let hashed: HashSet<_> = zeros.iter().map(|&v| Bits(F32::from_bits(v))).collect();
+    assert_eq!(hashed.len(), zeros.len());
+}
+
+#[test]
+fn test_partial_cmp() {
+    for a in f32s() {
+        for b in f32s() {
+            let expected = f64::from(a).partial_cmp(&f64::from(b));
+            assert_eq!(a.partial_cmp(&b), expected, "{:08x} {:08x}", a.0, b.0);
+            assert_eq!(a == b, expected == Some(cmp::Ordering::Equal));
+        }
+    }
+
+    // These round to the same f64, but are not equal
+    let a = F64::from_bits(0x4180000000000000);
+    let b = F64::from_bits(0x4180000000000001);
+    assert_eq!(f64::from(a), f64::from(b));
+    assert!(a < b && a != b);
+    assert!(-a > -b && -a != -b);
+
+    // Unnormalized encodings of the same value are equal
+    assert!(a == F64::from_bits(0x4208000000000000));
+    assert!(a == F64::from_bits(0x4e00000000000008));
+    assert!(F64::from_bits(0x8000000000000000) == F64::from_bits(0x7f00000000000000));
+}
+
+#[test]
+fn test_partial_cmp_mixed() {
+    for a in f32s() {
+        let wide = widen_f32(a);
+        for b in f32s() {
+            let expected = f64::from(a).partial_cmp(&f64::from(b));
+            assert_eq!(a.partial_cmp(&f64::from(b)), expected);
+            assert_eq!(
+                f64::from(b).partial_cmp(&a),
+                expected.map(cmp::Ordering::reverse)
+            );
+            assert_eq!(wide.partial_cmp(&f64::from(b)), expected);
+            assert_eq!(a.partial_cmp(&widen_f32(b)), expected);
+            assert_eq!(wide.partial_cmp(&b), expected);
+        }
+
+        let reference = f64::from(a);
+        assert!(a == reference);
+        assert_eq!(a == f32::from(a), f64::from(f32::from(a)) == reference);
+        assert_eq!(a.partial_cmp(&f64::NAN), None);
+        assert!(a < f64::INFINITY && a > f64::NEG_INFINITY);
+        assert!(wide < f32::INFINITY && wide > f32::NEG_INFINITY);
+        assert_eq!(f32::NAN.partial_cmp(&wide), None);
+    }
+
+    // IEEE subnormals: 16^-65 lies between the smallest f64 and the smallest f32
+    let tiny = F64::from_bits(0x0010000000000000);
+    assert!(tiny > f64::from_bits(1) && tiny < f32::from_bits(1));
+    assert!(-tiny < -f64::from_bits(1) && -tiny > -f32::from_bits(1));
+    assert!(F64::from_bits(0) == -f64::from_bits(0));
+
+    // Differences beyond f64 precision are still observed
+    let value = F64::from_bits(0x4180000000000001);
+    assert!(value > f64::from(value) && f64::from(value) < value);
+    assert!(-value < -f64::from(value));
+}
+
+#[test]
+fn test_total_cmp() {
+    let mut values: Vec<_> = f64s().map(TotalOrder).collect();
+    values.sort();
+    for pair in values.windows(2) {
+        let (a, b) = (pair[0].0, pair[1].0);
+        assert!(a <= b, "{:016x} {:016x}", a.0, b.0);
+        assert_eq!(a.total_cmp(&b) == cmp::Ordering::Equal, a.0 == b.0);
+        if a == b && a.0 != b.0 {
+            // Ties are broken by sign, then by encoding
+            let (a_negative, b_negative) = (a.is_sign_negative(), b.is_sign_negative());
+            assert!(a_negative && !b_negative || a_negative == b_negative && a.0 < b.0);
+        }
+    }
+
+    // Hashing agrees with equality, keeping every distinct encoding
+    let hashed: std::collections::HashSet<_> = values.iter().chain(values.iter()).collect();
+    let mut encodings: Vec<_> = values.iter().map(|v| v.0.to_bits()).collect();
+    encodings.dedup();
+    assert_eq!(hashed.len(), encodings.len());
+    let zeros = [0x00000000, 0x80000000, 0x42000000, 0x00000000];
+    let hashed: std::collections::HashSet<_> = zeros
+        .iter()
+        .map(|&v| TotalOrder(F32::from_bits(v)))
+        .collect();
+    assert_eq!(hashed.len(), 3);
+
+    for a in f32s() {
+        for b in f32s() {
+            let total = a.total_cmp(&b);
+            assert_eq!(b.total_cmp(&a), total.reverse());
+            if a != b {
+                assert_eq!(Some(total), a.partial_cmp(&b));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sort_key() {
+    fn is_normalized(bits: u64) -> bool {
+        bits & 0x00ff_ffff_ffff_ffff == 0 && bits & 0x7f00_0000_0000_0000 == 0
+            || bits & 0x00f0_0000_0000_0000 != 0
+    }
+
+    let mut by_key: Vec<_> = f32s()
+        .map(widen_f32)
+        .chain(f64s())
+        .filter(|v| is_normalized(v.to_bits()))
+        .collect();
+    let mut by_total_cmp = by_key.clone();
+    F64::sort_slice(&mut by_key);
+    by_total_cmp.sort_by(F64::total_cmp);
+    assert!(by_key.len() > 100);
+    assert!(by_key
+        .iter()
+        .map(|v| v.to_bits())
+        .eq(by_total_cmp.iter().map(|v| v.to_bits())));
+
+    let mut values: Vec<_> = f32s()
+        .filter(|v| is_normalized(u64::from(v.to_bits()) << 32))
+        .collect();
+    F32::sort_slice(&mut values);
+    for pair in values.windows(2) {
+        assert!(pair[0] <= pair[1]);
+        assert!(pair[0].sort_key() < pair[1].sort_key() || pair[0].0 == pair[1].0);
+    }
+}
+
+#[test]
+fn test_ibm_float() {
+    fn check<T: IbmFloat>(values: impl Iterator<Item = T>) {
+        let digits = T::FRACTION_DIGITS as i32;
+        let max = (1.0 - f64::from(T::RADIX).powi(-digits)) * 16f64.powi(127 - T::EXPONENT_BIAS);
+        assert_eq!(T::MAX.to_f64(), max);
+        assert_eq!(T::MIN_POSITIVE.to_f64(), 16f64.powi(-T::EXPONENT_BIAS - 1));
+
+        for value in values {
+            assert!(value <= T::MAX);
+            assert_eq!(T::from_bits(value.to_bits()).to_bits(), value.to_bits());
+            assert_eq!(
+                T::from_be_bytes(value.to_be_bytes()).to_bits(),
+                value.to_bits()
+            );
+            assert_eq!(value.to_be_bytes().as_ref().len() as u32 * 8, T::BITS);
+            assert_eq!(value.to_f64().to_bits(), Into::<f64>::into(value).to_bits());
+        }
+    }
+
+    check(f32s());
+    check(f64s());
+    check(f128s());
+}
+
+#[test]
+fn test_hex_float() {
+    for value in f32s() {
+        let hex = HexFloat::<32>::from(value);
+        assert_eq!(F32::from(hex).to_bits(), value.to_bits());
+        assert_eq!(f32::from(hex).to_bits(), f32::from(value).to_bits());
+        assert_eq!(f64::from(hex).to_bits(), f64::from(value).to_bits());
+        assert!(hex == HexFloat::<32>::from_be_slice(&value.to_be_bytes()).unwrap());
+        assert_eq!(format!("{:e}", hex), format!("{:e}", value));
+        assert_eq!(format!("{:E}", hex), format!("{:E}", value));
+
+        // Keep the leading 4 digits as a 24-bit float
+        let hex = HexFloat::<24>::from_bits(u64::from(value.to_bits() >> 8));
+        let truncated = F32::from_bits(value.to_bits() & !0xff);
+        assert_eq!(f32::from(hex).to_bits(), f32::from(truncated).to_bits());
+        assert_eq!(f64::from(hex).to_bits(), f64::from(truncated).to_bits());
+        assert!(hex.to_f64_exact() == truncated);
+    }
+
+    for value in f64s() {
+        let hex = HexFloat::<64>::from(value);
+        assert_eq!(F64::from(hex).to_bits(), value.to_bits());
+        assert_eq!(f32::from(hex).to_bits(), f32::from(value).to_bits());
+        assert_eq!(f64::from(hex).to_bits(), f64::from(value).to_bits());
+        assert_eq!(format!("{:?}", hex), format!("{:?}", value));
+
+        // Keep the leading 10 digits as a 48-bit float
+        let hex = HexFloat::<48>::from_bits(value.to_bits() >> 16);
+        let truncated = F64::from_bits(value.to_bits() & !0xffff);
+        assert_eq!(hex.to_f64_exact().to_bits(), truncated.to_bits());
+        assert_eq!(f32::from(hex).to_bits(), f32::from(truncated).to_bits());
+        assert_eq!(f64::from(hex).to_bits(), f64::from(truncated).to_bits());
+        assert!(hex == HexFloat::<48>::from_be_slice(&truncated.to_be_bytes()[..6]).unwrap());
+    }
+
+    for a in f64s() {
+        for b in f64s() {
+            let (ha, hb) = (
+                HexFloat::<36>::from_bits(a.to_bits() >> 28),
+                HexFloat::<36>::from_bits(b.to_bits() >> 28),
+            );
+            assert_eq!(
+                ha.partial_cmp(&hb),
+                ha.to_f64_exact().partial_cmp(&hb.to_f64_exact())
+            );
+        }
+    }
+    assert!(HexFloat::<36>::from_be_slice(&[0; 4]).is_none());
+    assert!(HexFloat::<36>::from_be_slice(&[0; 5]).is_none());
+}
+
+#[test]
+fn test_f128() {
+    for value in f64s() {
+        let wide = widen_f64(value);
+        assert!(wide.is_valid());
+        assert_eq!(f64::from(wide).to_bits(), f64::from(value).to_bits());
+        assert_eq!(f32::from(wide).to_bits(), f32::from(value).to_bits());
+        assert_eq!(format!("{:?}", wide), format!("{:?}", value));
+    }
+
+    for a in f128s() {
+        for b in f128s() {
+            let expected = F64::from_bits((a.to_bits() >> 64) as u64)
+                .partial_cmp(&F64::from_bits((b.to_bits() >> 64) as u64));
+            assert_eq!(a.partial_cmp(&b), expected);
+        }
+    }
+
+    // These round to the same f64, but are not equal
+    let a = F128::from_bits(0x4110000000000000_3300000000000000);
+    let b = F128::from_bits(0x4110000000000000_3300000000000001);
+    assert_eq!(f64::from(a), f64::from(b));
+    assert!(a < b && a != b);
+
+    // The low-order sign and characteristic are ignored
+    assert!(a == F128::from_bits(0x4110000000000000_0000000000000000));
+    assert!(a == F128::from_bits(0x4110000000000000_ff00000000000000));
+    assert!(!F128::from_bits(0x4110000000000000_ff00000000000000).is_valid());
+}
+
+#[test]
+fn test_complex() {
+    for (re, im) in f32s().zip(f32s().skip(1)) {
+        let value = C32::new(re, im);
+        let bytes = value.to_be_bytes();
+        assert_eq!(bytes[..4], re.to_be_bytes());
+        assert_eq!(bytes[4..], im.to_be_bytes());
+        assert_eq!(C32::from_be_bytes(bytes).re.to_bits(), re.to_bits());
+        assert_eq!(C32::from_be_bytes(bytes).im.to_bits(), im.to_bits());
+        assert!(C32::from((re, im)) == value);
+    }
+
+    for (re, im) in f64s().zip(f64s().skip(1)) {
+        let value = C64::new(re, im);
+        let bytes = value.to_be_bytes();
+        assert_eq!(bytes[..8], re.to_be_bytes());
+        assert_eq!(bytes[8..], im.to_be_bytes());
+        assert_eq!(C64::from_be_bytes(bytes).re.to_bits(), re.to_bits());
+        assert_eq!(C64::from_be_bytes(bytes).im.to_bits(), im.to_bits());
+        assert!(C64::from((re, im)) == value);
+    }
+}
+
+#[cfg(feature = "num-complex")]
+#[test]
+fn test_num_complex() {
+    use num_complex::Complex;
+
+    for (re, im) in f32s().zip(f32s().skip(1)) {
+        let value = C32::new(re, im);
+        assert_eq!(
+            Complex::<f32>::from(value),
+            Complex::new(re.into(), im.into())
+        );
+        assert_eq!(
+            Complex::<f64>::from(value),
+            Complex::new(re.into(), im.into())
+        );
+    }
+
+    for (re, im) in f64s().zip(f64s().skip(1)) {
+        let value = C64::new(re, im);
+        assert_eq!(
+            Complex::<f32>::from(value),
+            Complex::new(re.into(), im.into())
+        );
+        assert_eq!(
+            Complex::<f64>::from(value),
+            Complex::new(re.into(), im.into())
+        );
+    }
+}
+
+#[cfg(feature = "num-rational")]
+#[test]
+fn test_partial_cmp_rational() {
+    use num_rational::BigRational;
+
+    for a in f64s() {
+        for b in f64s() {
+            let expected = BigRational::from(a).cmp(&BigRational::from(b));
+            assert_eq!(
+                a.partial_cmp(&b),
+                Some(expected),
+                "{:016x} {:016x}",
+                a.0,
+                b.0
+            );
+        }
+    }
+}
+
+#[cfg(feature = "num-rational")]
+#[test]
+fn test_rational() {
+    use num_rational::BigRational;
+
+    for value in f32s() {
+        let reference = BigRational::from_float(f64::from(value)).unwrap();
+        assert_eq!(BigRational::from(value), reference);
+
+        assert_eq!(BigRational::from(widen_f32(value)), reference);
+    }
+
+    for value in f64s() {
+        // Clearing the low bit must change the value by exactly that bit's weight
+        let even = F64::from_bits(value.to_bits() & !1);
+        let lsb = F64::from_bits(value.to_bits() & 0xff00_0000_0000_0001);
+        let delta = BigRational::from(value) - BigRational::from(even);
+        assert_eq!(delta, BigRational::from(lsb));
+    }
 }
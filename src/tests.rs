@@ -0,0 +1,1032 @@
+use crate::*;
+
+#[test]
+fn test_add() {
+    let cases: &[(u32, u32, u32)] = &[
+        // 1 + 1 = 2
+        (0x41100000, 0x41100000, 0x41200000),
+        // 8 + 8 carries into a new digit
+        (0x41800000, 0x41800000, 0x42100000),
+        // -1 + 0.5 normalizes
+        (0xc1100000, 0x40800000, 0xc0800000),
+        // The guard digit keeps 1 - 0.ffffff exact
+        (0x41100000, 0xc0ffffff, 0x3b100000),
+        // Digits beyond the guard digit are lost before subtracting
+        (0x41100000, 0xbfffffff, 0x40f00001),
+        // Dirty zeros participate in alignment
+        (0x46000000, 0x41123456, 0x41120000),
+        // Zero sums are true zeros
+        (0x41100000, 0xc1100000, 0x00000000),
+        (0xc2123456, 0x42123456, 0x00000000),
+        // Underflow produces a true zero
+        (0x00110000, 0x80100000, 0x00000000),
+        // Overflow wraps the characteristic
+        (0x7fffffff, 0x7fffffff, 0x001fffff),
+    ];
+    for &(a, b, sum) in cases {
+        let (a, b) = (F32::from_bits(a), F32::from_bits(b));
+        assert_eq!((a + b).to_bits(), sum, "{:08x} + {:08x}", a.0, b.0);
+        assert_eq!((b + a).to_bits(), sum, "{:08x} + {:08x}", b.0, a.0);
+        assert_eq!((a - -b).to_bits(), sum, "{:08x} - {:08x}", a.0, (-b).0);
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c.to_bits(), sum);
+        c -= b;
+        assert_eq!(c.to_bits(), (F32::from_bits(sum) - b).to_bits());
+    }
+
+    // 1 + 16^-14 fits, but 1 + 16^-15 only reaches the guard digit
+    let one = F64::from_bits(0x4110000000000000);
+    assert_eq!(
+        (one + F64::from_bits(0x3410000000000000)).to_bits(),
+        0x4110000000000001
+    );
+    assert_eq!(
+        (one + F64::from_bits(0x3310000000000000)).to_bits(),
+        0x4110000000000000
+    );
+    assert_eq!(
+        (one - F64::from_bits(0x3310000000000000)).to_bits(),
+        0x40ffffffffffffff
+    );
+
+    for a in f32s() {
+        for b in f32s() {
+            assert_eq!((a + b).to_bits(), (b + a).to_bits());
+            assert!(a - b == -(b - a));
+        }
+    }
+
+    // Sums of normalized values with nearby characteristics are exact in F64
+    for a in f32s() {
+        for b in f32s() {
+            let (ca, cb) = ((a.0 >> 24) & 0x7f, (b.0 >> 24) & 0x7f);
+            if a.0 & 0x00f0_0000 == 0 || b.0 & 0x00f0_0000 == 0 || ca.max(cb) - ca.min(cb) > 4 {
+                continue;
+            }
+            if ca.max(cb) >= 0x7f || ca.min(cb) < 8 {
+                continue;
+            }
+            assert!(widen_f32(a) + widen_f32(b) == f64::from(a) + f64::from(b));
+        }
+    }
+}
+
+#[test]
+fn test_add_unnormalized() {
+    let cases: &[(u32, u32, u32)] = &[
+        (0x41100000, 0x41100000, 0x41200000),
+        // Carries still shift right
+        (0x41800000, 0x41800000, 0x42100000),
+        // Leading zeros are kept
+        (0x42010000, 0x42010000, 0x42020000),
+        (0x41100000, 0xc0f00000, 0x41010000),
+        // The guard digit is truncated without normalizing
+        (0x41100000, 0xc0ffffff, 0x41000000),
+        (0xc1100000, 0x40ffffff, 0xc1000000),
+        // Dirty zeros participate in alignment
+        (0x46000000, 0x41123456, 0x46000001),
+        // Zero sums are true zeros
+        (0x42010000, 0xc2010000, 0x00000000),
+        // Characteristics never decrease, so tiny values do not underflow
+        (0x00110000, 0x80100000, 0x00010000),
+        // Overflow wraps the characteristic
+        (0x7fffffff, 0x7fffffff, 0x001fffff),
+    ];
+    for &(a, b, sum) in cases {
+        let (a, b) = (F32::from_bits(a), F32::from_bits(b));
+        assert_eq!(
+            a.add_unnormalized(b).to_bits(),
+            sum,
+            "{:08x} + {:08x}",
+            a.0,
+            b.0
+        );
+        assert_eq!(
+            b.add_unnormalized(a).to_bits(),
+            sum,
+            "{:08x} + {:08x}",
+            b.0,
+            a.0
+        );
+        assert_eq!(
+            a.sub_unnormalized(-b).to_bits(),
+            sum,
+            "{:08x} - {:08x}",
+            a.0,
+            (-b).0
+        );
+    }
+
+    // Normalized results match `+`, since normalization is all that differs
+    for a in f32s() {
+        for b in f32s() {
+            let sum = a.add_unnormalized(b);
+            if sum.0 & 0x00f0_0000 != 0 {
+                assert_eq!(
+                    sum.to_bits(),
+                    (a + b).to_bits(),
+                    "{:08x} + {:08x}",
+                    a.0,
+                    b.0
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_mul() {
+    let cases: &[(u32, u32, u32)] = &[
+        // 2 * 3 = 6
+        (0x41200000, 0x41300000, 0x41600000),
+        (0xc276a000, 0x41100000, 0xc276a000),
+        // Operands are normalized first
+        (0x42010000, 0x41200000, 0x41200000),
+        // The product is truncated
+        (0x40ffffff, 0x40ffffff, 0x40fffffe),
+        // Zero operands, including dirty zeros, produce true zeros
+        (0x00000000, 0x41100000, 0x00000000),
+        (0x45000000, 0xc1100000, 0x00000000),
+        (0xc1100000, 0x80000000, 0x00000000),
+        // Underflow produces a true zero
+        (0x20100000, 0x20100000, 0x00000000),
+        // Overflow wraps the characteristic
+        (0x61100000, 0x61100000, 0x01100000),
+    ];
+    for &(a, b, product) in cases {
+        let (a, b) = (F32::from_bits(a), F32::from_bits(b));
+        assert_eq!((a * b).to_bits(), product, "{:08x} * {:08x}", a.0, b.0);
+        assert_eq!((b * a).to_bits(), product, "{:08x} * {:08x}", b.0, a.0);
+
+        let mut c = a;
+        c *= b;
+        assert_eq!(c.to_bits(), product);
+    }
+
+    // (1 + 16^-13)^2 = 1 + 2 * 16^-13 + 16^-26, truncated
+    let a = F64::from_bits(0x4110000000000001);
+    assert_eq!((a * a).to_bits(), 0x4110000000000002);
+
+    // Products of F32s are exact in F64 and f64, and truncated in F32
+    for a in f32s() {
+        for b in f32s() {
+            let exact = f64::from(a) * f64::from(b);
+            if exact == 0.0 || exact.abs() >= pow16(63) || exact.abs() < pow16(-65) {
+                continue;
+            }
+            let product = widen_f32(a) * widen_f32(b);
+            assert!(product == exact, "{:08x} * {:08x}", a.0, b.0);
+            assert_eq!(u64::from((a * b).to_bits()), product.to_bits() >> 32);
+        }
+    }
+}
+
+#[test]
+fn test_mul_widen_f32() {
+    for a in f32s() {
+        for b in f32s() {
+            let exact = f64::from(a) * f64::from(b);
+            let product = a.mul_wide(b);
+            if exact == 0.0 {
+                assert_eq!(product.to_bits(), 0);
+            } else if exact.abs() < pow16(63) && exact.abs() >= pow16(-65) {
+                assert_eq!(product.to_bits(), (widen_f32(a) * widen_f32(b)).to_bits());
+            }
+        }
+    }
+
+    for a in f64s() {
+        for b in f64s() {
+            let product = a.mul_wide(b);
+            assert!(product.is_valid());
+
+            // The truncated product is the high-order part
+            assert_eq!((product.to_bits() >> 64) as u64, (a * b).to_bits());
+
+            // Multiplying by one is exact
+            let one = F64::from_bits(0x4110000000000000);
+            if a.0 & 0x00f0_0000_0000_0000 != 0 {
+                assert_eq!(a.mul_wide(one).to_bits(), widen_f64(a).to_bits());
+            }
+        }
+    }
+
+    // Underflow and overflow are handled like `*`
+    let tiny = F64::from_bits(0x2010000000000000);
+    assert_eq!(tiny.mul_wide(tiny).to_bits(), 0);
+    let huge = F64::from_bits(0x6110000000000000);
+    assert_eq!(
+        huge.mul_wide(huge).to_bits(),
+        0x0110000000000000_7300000000000000
+    );
+}
+
+#[test]
+fn test_mul_add() {
+    for a in f32s() {
+        for b in f32s() {
+            let exact = f64::from(a) * f64::from(b);
+            if exact == 0.0 || exact.abs() >= pow16(62) || exact.abs() < pow16(-50) {
+                continue;
+            }
+
+            // Adding zero only normalizes and truncates the product
+            let product = a * b;
+            assert_eq!(a.mul_add(b, F32::from_bits(0)).to_bits(), product.to_bits());
+            assert_eq!(a.mul_sub(b, F32::from_bits(0)).to_bits(), product.to_bits());
+
+            // Subtracting the truncated product leaves what truncation discarded
+            let remainder = a.mul_wide(b) - widen_f32(product);
+            let expected = (remainder.to_bits() >> 32) as u32;
+            assert_eq!(
+                a.mul_sub(b, product).to_bits(),
+                expected,
+                "{:08x} * {:08x}",
+                a.0,
+                b.0
+            );
+            assert_eq!(a.mul_add(b, -product).to_bits(), expected);
+        }
+    }
+
+    // Sums are formed at double length, with a guard digit
+    let a = F64::from_bits(0x4110000000000001);
+    let b = F64::from_bits(0x2710000000000000);
+    assert_eq!(a.mul_add(a, b).to_bits(), 0x4110000000000002);
+    assert_eq!(a.mul_sub(a, -b).to_bits(), 0x4110000000000002);
+    assert_eq!(a.mul_sub(a, a * a).to_bits(), 0x2710000000000000);
+}
+
+#[test]
+fn test_div() {
+    let cases: &[(u32, u32, u32)] = &[
+        // 6 / 3 = 2
+        (0x41600000, 0x41300000, 0x41200000),
+        // The quotient is truncated
+        (0x41100000, 0x41300000, 0x40555555),
+        (0xc1100000, 0x41300000, 0xc0555555),
+        (0x41100000, 0xc1300000, 0xc0555555),
+        // Operands are normalized first
+        (0x42010000, 0x41100000, 0x41100000),
+        (0x41100000, 0x42010000, 0x41100000),
+        // Zero dividends, including dirty zeros, produce true zeros
+        (0x00000000, 0x41100000, 0x00000000),
+        (0xc5000000, 0x41100000, 0x00000000),
+        // Underflow produces a true zero
+        (0x00100000, 0x41200000, 0x00000000),
+        // Overflow wraps the characteristic
+        (0x7f100000, 0x00100000, 0x40100000),
+    ];
+    for &(a, b, quotient) in cases {
+        let (a, b) = (F32::from_bits(a), F32::from_bits(b));
+        assert_eq!(
+            (a / b).map(F32::to_bits),
+            Ok(quotient),
+            "{:08x} / {:08x}",
+            a.0,
+            b.0
+        );
+    }
+
+    for b in [0x00000000, 0x80000000, 0x45000000] {
+        let b = F32::from_bits(b);
+        assert_eq!(
+            (F32::from_bits(0x41100000) / b).err(),
+            Some(Exception::FloatingPointDivide)
+        );
+        assert_eq!(
+            (F32::from_bits(0x00000000) / b).err(),
+            Some(Exception::FloatingPointDivide)
+        );
+    }
+
+    let third = F64::from_bits(0x4110000000000000) / F64::from_bits(0x4130000000000000);
+    assert_eq!(third.map(F64::to_bits), Ok(0x4055555555555555));
+
+    // Exact products divide back exactly to normalized values
+    for a in f32s().filter(|a| a.0 & 0x00f0_0000 != 0) {
+        for b in f32s() {
+            let exact = f64::from(a) * f64::from(b);
+            if exact == 0.0 || exact.abs() >= pow16(63) || exact.abs() < pow16(-65) {
+                continue;
+            }
+            let quotient = (widen_f32(a) * widen_f32(b) / widen_f32(b)).unwrap();
+            assert!(quotient == widen_f32(a), "{:08x} * {:08x}", a.0, b.0);
+        }
+    }
+}
+
+#[test]
+fn test_sqrt() {
+    // Zeros of either sign, including dirty zeros, produce true zeros
+    for zero in [0x00000000, 0x80000000, 0x45000000, 0xc5000000] {
+        assert_eq!(F32::from_bits(zero).sqrt().map(F32::to_bits), Ok(0));
+    }
+
+    // Each root is within half a unit in the last place of the exact root
+    let extended = |v: F32| widen_f32(v).mul_wide(F64::from_bits(0x4110000000000000));
+    for value in f32s() {
+        let root = match value.sqrt() {
+            Ok(root) => root,
+            Err(e) => {
+                assert_eq!(e, Exception::SquareRoot);
+                assert!(value.is_sign_negative() && value != F32::from_bits(0));
+                continue;
+            }
+        };
+        if value == F32::from_bits(0) {
+            continue;
+        }
+        assert_ne!(root.0 & 0x00f0_0000, 0, "{:08x} is not normalized", root.0);
+
+        // Keep the squares below in range
+        if !(0x08..0x78).contains(&((value.0 >> 24) & 0x7f)) {
+            continue;
+        }
+
+        // One half in the last place of the root, as an F64
+        let half_ulp =
+            F64::from_bits((u64::from(root.0 & 0x7f00_0000) << 32) | 0x0000_0000_8000_0000);
+        let (low, high) = (widen_f32(root) - half_ulp, widen_f32(root) + half_ulp);
+        assert!(
+            low.mul_wide(low) <= extended(value),
+            "sqrt({:08x}) = {:08x}",
+            value.0,
+            root.0
+        );
+        assert!(
+            extended(value) < high.mul_wide(high),
+            "sqrt({:08x}) = {:08x}",
+            value.0,
+            root.0
+        );
+    }
+
+    // Squares of short fractions have exact roots
+    for value in f64s() {
+        let value = F64::from_bits(value.to_bits() & 0xffff_ffff_f000_0000).abs();
+        if value.0 & 0x00f0_0000_0000_0000 == 0 || !(0x21..0x60).contains(&(value.0 >> 56)) {
+            continue;
+        }
+        assert_eq!(
+            (value * value).sqrt().map(F64::to_bits),
+            Ok(value.to_bits())
+        );
+    }
+}
+
+#[test]
+fn test_context() {
+    // With both masks clear, the context matches the operators
+    let mut context = HfpContext::new();
+    for a in f32s() {
+        for b in f32s() {
+            assert_eq!(context.add(a, b).to_bits(), (a + b).to_bits());
+            assert_eq!(context.sub(a, b).to_bits(), (a - b).to_bits());
+            assert_eq!(
+                context.add_unnormalized(a, b).to_bits(),
+                a.add_unnormalized(b).to_bits()
+            );
+            assert_eq!(
+                context.sub_unnormalized(a, b).to_bits(),
+                a.sub_unnormalized(b).to_bits()
+            );
+            assert_eq!(context.mul(a, b).to_bits(), (a * b).to_bits());
+            assert_eq!(context.mul_wide(a, b).to_bits(), a.mul_wide(b).to_bits());
+            assert_eq!(
+                context.mul_add(a, b, a).to_bits(),
+                a.mul_add(b, a).to_bits()
+            );
+            assert_eq!(
+                context.mul_sub(a, b, a).to_bits(),
+                a.mul_sub(b, a).to_bits()
+            );
+            assert_eq!(
+                context.div(a, b).map(F32::to_bits),
+                (a / b).map(F32::to_bits)
+            );
+        }
+        assert_eq!(
+            context.sqrt(a).map(F32::to_bits),
+            a.sqrt().map(F32::to_bits)
+        );
+        assert_eq!(context.halve(a).to_bits(), a.halve().to_bits());
+    }
+    for a in f64s() {
+        for b in f64s() {
+            assert_eq!(context.mul_wide(a, b).to_bits(), a.mul_wide(b).to_bits());
+        }
+    }
+    assert!(context.raised(Exception::ExponentOverflow));
+    assert!(context.raised(Exception::FloatingPointDivide));
+    assert!(context.raised(Exception::SquareRoot));
+    assert!(!context.raised(Exception::ExponentUnderflow));
+    assert!(!context.raised(Exception::Significance));
+
+    context.clear_flags();
+    assert_eq!(context, HfpContext::new());
+
+    // The significance mask keeps the characteristic of zero sums
+    let a = F32::from_bits(0x42123456);
+    let mut context = HfpContext::new().with_significance_mask(true);
+    assert!(context.significance_mask() && !context.exponent_underflow_mask());
+    assert_eq!(
+        context.add(a, F32::from_bits(0x41100000)).to_bits(),
+        0x42133456
+    );
+    assert!(!context.raised(Exception::Significance));
+    assert_eq!(context.sub(a, a).to_bits(), 0x42000000);
+    assert_eq!(context.add_unnormalized(-a, a).to_bits(), 0x42000000);
+    assert!(context.raised(Exception::Significance));
+
+    // Multiply-and-add never reports significance
+    let two = F32::from_bits(0x41200000);
+    let four = F32::from_bits(0x41400000);
+    let mut context = HfpContext::new().with_significance_mask(true);
+    assert_eq!(context.mul_add(two, two, -four).to_bits(), 0x00000000);
+    assert_eq!(context.mul_sub(two, two, four).to_bits(), 0x00000000);
+    assert!(!context.raised(Exception::Significance));
+
+    // The exponent underflow mask wraps the characteristic
+    let tiny = F64::from_bits(0x2010000000000000);
+    let mut context = HfpContext::new().with_exponent_underflow_mask(true);
+    assert_eq!(context.mul(tiny, tiny).to_bits(), 0x7f10000000000000);
+    assert!(context.raised(Exception::ExponentUnderflow));
+    assert!(!context.raised(Exception::ExponentOverflow));
+    assert_eq!(
+        context
+            .div(tiny, F64::from_bits(0x6010000000000000))
+            .map(F64::to_bits),
+        Ok(0x0110000000000000)
+    );
+    assert_eq!(
+        context
+            .div(tiny, F64::from_bits(0x6210000000000000))
+            .map(F64::to_bits),
+        Ok(0x7f10000000000000)
+    );
+    let zero = F64::from_bits(0);
+    assert_eq!(
+        context.mul_add(tiny, tiny, zero).to_bits(),
+        0x7f10000000000000
+    );
+    assert_eq!(
+        context.mul_sub(tiny, tiny, zero).to_bits(),
+        0x7f10000000000000
+    );
+    assert_eq!(
+        context.halve(F64::from_bits(0x0010000000000000)).to_bits(),
+        0x7f80000000000000
+    );
+    context.clear_flags();
+    assert_eq!(
+        context.mul_wide(tiny, tiny).to_bits(),
+        0x7f10000000000000_7100000000000000
+    );
+    assert!(context.raised(Exception::ExponentUnderflow));
+    context.clear_flags();
+    let tiny32 = F32::from_bits(0x20100000);
+    assert_eq!(
+        context.mul_wide(tiny32, tiny32).to_bits(),
+        0x7f10000000000000
+    );
+    assert!(context.raised(Exception::ExponentUnderflow));
+
+    // The same operations underflow to true zeros with the mask clear
+    let mut context = HfpContext::new();
+    assert_eq!(
+        context
+            .div(tiny, F64::from_bits(0x6210000000000000))
+            .map(F64::to_bits),
+        Ok(0)
+    );
+    assert_eq!(context.mul_add(tiny, tiny, zero).to_bits(), 0);
+    assert_eq!(context.mul_sub(tiny, tiny, zero).to_bits(), 0);
+    assert_eq!(
+        context.halve(F64::from_bits(0x0010000000000000)).to_bits(),
+        0
+    );
+    assert_eq!(context.mul_wide(tiny, tiny).to_bits(), 0);
+    assert_eq!(context.mul_wide(tiny32, tiny32).to_bits(), 0);
+    assert!(!context.raised(Exception::ExponentUnderflow));
+
+    // Wide products still report overflow
+    let huge = F64::from_bits(0x6110000000000000);
+    assert_eq!(
+        context.mul_wide(huge, huge).to_bits(),
+        0x0110000000000000_7300000000000000
+    );
+    assert!(context.raised(Exception::ExponentOverflow));
+
+    // Suppressed operations report their exceptions as errors
+    let mut context = HfpContext::new();
+    assert_eq!(
+        context.div(tiny, F64::from_bits(0)).err(),
+        Some(Exception::FloatingPointDivide)
+    );
+    assert!(context.raised(Exception::FloatingPointDivide));
+    assert!(!context.raised(Exception::SquareRoot));
+    assert_eq!(context.sqrt(-tiny).err(), Some(Exception::SquareRoot));
+    assert!(context.raised(Exception::SquareRoot));
+}
+
+#[test]
+fn test_compare() {
+    let cases: &[(u32, u32, ConditionCode)] = &[
+        (0x41100000, 0x41100000, ConditionCode::Cc0),
+        (0x41100000, 0x41200000, ConditionCode::Cc1),
+        (0x41100000, 0x40ffffff, ConditionCode::Cc2),
+        (0xc1100000, 0x40ffffff, ConditionCode::Cc1),
+        // Zeros of any sign and characteristic are equal
+        (0x00000000, 0x80000000, ConditionCode::Cc0),
+        (0x00000000, 0x7f000000, ConditionCode::Cc0),
+        // Unnormalized values compare by value
+        (0x42010000, 0x41100000, ConditionCode::Cc0),
+        (0x42010000, 0x41100001, ConditionCode::Cc1),
+        // Differences beyond the guard digit are lost
+        (0x42000000, 0x3b100000, ConditionCode::Cc0),
+        (0x42000000, 0x3c100000, ConditionCode::Cc1),
+        (0x48000000, 0x41100000, ConditionCode::Cc0),
+        (0x47000000, 0x41100000, ConditionCode::Cc1),
+    ];
+    for &(a, b, cc) in cases {
+        let (a, b) = (F32::from_bits(a), F32::from_bits(b));
+        assert_eq!(a.compare(b), cc, "{:08x} <=> {:08x}", a.0, b.0);
+        let reversed = match cc {
+            ConditionCode::Cc1 => ConditionCode::Cc2,
+            ConditionCode::Cc2 => ConditionCode::Cc1,
+            cc => cc,
+        };
+        assert_eq!(b.compare(a), reversed, "{:08x} <=> {:08x}", b.0, a.0);
+    }
+
+    // Comparison agrees with subtraction when no exception can hide the difference
+    let mut context = HfpContext::new()
+        .with_exponent_underflow_mask(true)
+        .with_significance_mask(true);
+    for a in f32s() {
+        for b in f32s() {
+            let (_, cc) = context.sub(a, b).load_and_test();
+            assert_eq!(a.compare(b), cc, "{:08x} <=> {:08x}", a.0, b.0);
+        }
+    }
+    for a in f64s() {
+        for b in f64s() {
+            let (_, cc) = context.sub(a, b).load_and_test();
+            assert_eq!(a.compare(b), cc, "{:016x} <=> {:016x}", a.0, b.0);
+        }
+    }
+}
+
+#[test]
+fn test_load_and_test() {
+    let cases: &[(u32, ConditionCode)] = &[
+        (0x00000000, ConditionCode::Cc0),
+        (0x80000000, ConditionCode::Cc0),
+        (0x7f000000, ConditionCode::Cc0),
+        (0x41100000, ConditionCode::Cc2),
+        (0xc1100000, ConditionCode::Cc1),
+        (0x00000001, ConditionCode::Cc2),
+        (0xff000001, ConditionCode::Cc1),
+    ];
+    for &(bits, cc) in cases {
+        let (value, actual) = F32::from_bits(bits).load_and_test();
+        assert_eq!(value.to_bits(), bits);
+        assert_eq!(actual, cc, "{:08x}", bits);
+
+        let bits = u64::from(bits) << 32;
+        let (value, actual) = F64::from_bits(bits).load_and_test();
+        assert_eq!(value.to_bits(), bits);
+        assert_eq!(actual, cc, "{:016x}", bits);
+    }
+}
+
+#[test]
+fn test_load_sign() {
+    for value in f32s() {
+        let (_, cc) = value.load_and_test();
+        let (complement, complement_cc) = value.load_complement();
+        let (positive, positive_cc) = value.load_positive();
+        let (negative, negative_cc) = value.load_negative();
+        assert_eq!(complement.to_bits(), (-value).to_bits());
+        assert_eq!(positive.to_bits(), value.abs().to_bits());
+        assert_eq!(negative.to_bits(), (-value.abs()).to_bits());
+        match cc {
+            ConditionCode::Cc0 => {
+                assert_eq!(complement_cc, ConditionCode::Cc0);
+                assert_eq!(positive_cc, ConditionCode::Cc0);
+                assert_eq!(negative_cc, ConditionCode::Cc0);
+            }
+            _ => {
+                assert_ne!(complement_cc, cc);
+                assert_ne!(complement_cc, ConditionCode::Cc0);
+                assert_eq!(positive_cc, ConditionCode::Cc2);
+                assert_eq!(negative_cc, ConditionCode::Cc1);
+            }
+        }
+    }
+    for value in f64s() {
+        let (complement, complement_cc) = value.load_complement();
+        assert_eq!(complement.to_bits(), (-value).to_bits());
+        assert_eq!(complement_cc, (-value).load_and_test().1);
+        assert_eq!(value.load_positive().1, value.abs().load_and_test().1);
+        assert_eq!(value.load_negative().1, (-value.abs()).load_and_test().1);
+    }
+}
+
+#[test]
+fn test_halve() {
+    let cases: &[(u32, u32)] = &[
+        (0x41200000, 0x41100000),
+        (0x41100000, 0x40800000),
+        (0xc1100000, 0xc0800000),
+        // The bit shifted out of the last digit is truncated
+        (0x41ffffff, 0x417fffff),
+        (0x411fffff, 0x40fffff8),
+        // Unnormalized values are normalized
+        (0x43000100, 0x3f800000),
+        // Zero fractions are true zeros
+        (0xc2000000, 0x00000000),
+        // Underflow produces a true zero
+        (0x00100000, 0x00000000),
+    ];
+    for &(value, half) in cases {
+        assert_eq!(
+            F32::from_bits(value).halve().to_bits(),
+            half,
+            "{:08x}",
+            value
+        );
+    }
+
+    // Halving truncates the exact half, just like multiplying by one half
+    let one_half = F32::from_bits(0x40800000);
+    for value in f32s() {
+        assert_eq!(
+            value.halve().to_bits(),
+            (value * one_half).to_bits(),
+            "{:08x}",
+            value.0
+        );
+    }
+    let one_half = F64::from_bits(0x4080000000000000);
+    for value in f64s() {
+        assert_eq!(
+            value.halve().to_bits(),
+            (value * one_half).to_bits(),
+            "{:016x}",
+            value.0
+        );
+    }
+
+    // The exponent underflow mask keeps the fraction
+    let mut context = HfpContext::new().with_exponent_underflow_mask(true);
+    assert_eq!(
+        context.halve(F32::from_bits(0x00100000)).to_bits(),
+        0x7f800000
+    );
+    assert!(context.raised(Exception::ExponentUnderflow));
+}
+
+#[test]
+fn test_load_rounded() {
+    let cases: &[(u64, u32)] = &[
+        (0x4112345670000000, 0x41123456),
+        (0x411234567fffffff, 0x41123456),
+        (0x4112345680000000, 0x41123457),
+        (0xc1123456ffffffff, 0xc1123457),
+        // Carries shift the fraction right
+        (0x41ffffff80000000, 0x42100000),
+        // Overflow wraps the characteristic
+        (0x7fffffff80000000, 0x00100000),
+        // Unnormalized values and zeros are not normalized
+        (0x4200001280000000, 0x42000013),
+        (0xc500000000000000, 0xc5000000),
+    ];
+    for &(value, rounded) in cases {
+        assert_eq!(
+            F64::from_bits(value).load_rounded().to_bits(),
+            rounded,
+            "{:016x}",
+            value
+        );
+    }
+
+    // Widening is exact, so rounding undoes it
+    for value in f32s() {
+        assert_eq!(widen_f32(value).load_rounded().to_bits(), value.to_bits());
+    }
+    for value in f128s() {
+        assert_eq!(
+            value.load_rounded().to_bits(),
+            (value.to_bits() >> 64) as u64
+        );
+    }
+
+    // The low-order sign and characteristic do not participate
+    let value = F128::from_bits(0x4110000000000000_b380000000000000);
+    assert_eq!(value.load_rounded().to_bits(), 0x4110000000000001);
+    let value = F128::from_bits(0xc1ffffffffffffff_ff80000000000000);
+    assert_eq!(value.load_rounded().to_bits(), 0xc210000000000000);
+
+    // The context matches, and reports overflow from a carry at characteristic 0x7f
+    let mut context = HfpContext::new();
+    for value in f64s() {
+        assert_eq!(
+            context.load_rounded(value).to_bits(),
+            value.load_rounded().to_bits()
+        );
+    }
+    context.clear_flags();
+    let value = F64::from_bits(0x7fffffff70000000);
+    assert_eq!(context.load_rounded(value).to_bits(), 0x7fffffff);
+    assert!(!context.raised(Exception::ExponentOverflow));
+    let value = F64::from_bits(0x7fffffff80000000);
+    assert_eq!(context.load_rounded(value).to_bits(), 0x00100000);
+    assert!(context.raised(Exception::ExponentOverflow));
+
+    let mut context = HfpContext::new();
+    let value = F128::from_bits(0x7fffffffffffffff_f170000000000000);
+    assert_eq!(context.load_rounded(value).to_bits(), 0x7fffffffffffffff);
+    assert!(!context.raised(Exception::ExponentOverflow));
+    let value = F128::from_bits(0x7fffffffffffffff_f180000000000000);
+    assert_eq!(context.load_rounded(value).to_bits(), 0x0010000000000000);
+    assert!(context.raised(Exception::ExponentOverflow));
+}
+
+pub(crate) fn f32s() -> impl Iterator<Item = F32> {
+    [
+        0x00000000, 0x80000000, 0x00000001, 0x80000001, 0x3f000000, 0xbf000000, 0x7f000000,
+        0xff000000, 0x1b100000, 0x9b100000, 0x1b200000, 0x9b200000, 0x1b400000, 0x9b400000,
+        0x1b400001, 0x9b400001, 0x1b800000, 0x9b800000, 0x1bbfffff, 0x9bbfffff, 0x1bc00000,
+        0x9bc00000, 0x1da7bfff, 0x9da7bfff, 0x1da7c000, 0x9da7c000, 0x1da84000, 0x9da84000,
+        0x1da84001, 0x9da84001, 0x1da8bfff, 0x9da8bfff, 0x1da8c000, 0x9da8c000, 0x1da94000,
+        0x9da94000, 0x1da94001, 0x9da94001, 0x1da9bfff, 0x9da9bfff, 0x1da9c000, 0x9da9c000,
+        0x1daa4000, 0x9daa4000, 0x1daa4001, 0x9daa4001, 0x1fffffff, 0x9fffffff, 0x20fffff4,
+        0xa0fffff4, 0x20fffff5, 0xa0fffff5, 0x20fffff6, 0xa0fffff6, 0x20fffff7, 0xa0fffff7,
+        0x20fffff8, 0xa0fffff8, 0x20fffff9, 0xa0fffff9, 0x20fffffa, 0xa0fffffa, 0x20fffffb,
+        0xa0fffffb, 0x20fffffc, 0xa0fffffc, 0x20fffffd, 0xa0fffffd, 0x20fffffe, 0xa0fffffe,
+        0x20ffffff, 0xa0ffffff, 0x21100000, 0xa1100000, 0x21200000, 0xa1200000, 0x213fffff,
+        0xa13fffff, 0x21400000, 0xa1400000, 0x40800000, 0xc0800000, 0x46000001, 0xc6000001,
+        0x45000010, 0xc5000010, 0x44000100, 0xc4000100, 0x43001000, 0xc3001000, 0x42010000,
+        0xc2010000, 0x41100000, 0xc1100000, 0x41200000, 0xc1200000, 0x41300000, 0xc1300000,
+        0x41400000, 0xc1400000, 0x41800000, 0xc1800000, 0x48000001, 0xc8000001, 0x48000002,
+        0xc8000002, 0x48000004, 0xc8000004, 0x48000008, 0xc8000008, 0x48000010, 0xc8000010,
+        0x48000020, 0xc8000020, 0x48000040, 0xc8000040, 0x48000080, 0xc8000080, 0x48000100,
+        0xc8000100, 0x48000200, 0xc8000200, 0x48000400, 0xc8000400, 0x48000800, 0xc8000800,
+        0x48001000, 0xc8001000, 0x48002000, 0xc8002000, 0x48004000, 0xc8004000, 0x48008000,
+        0xc8008000, 0x48010000, 0xc8010000, 0x48020000, 0xc8020000, 0x48040000, 0xc8040000,
+        0x48080000, 0xc8080000, 0x48100000, 0xc8100000, 0x48200000, 0xc8200000, 0x48400000,
+        0xc8400000, 0x48800000, 0xc8800000, 0x60ffffff, 0xe0ffffff, 0x61100000, 0xe1100000,
+        0x61200000, 0xe1200000, 0x61400000, 0xe1400000, 0x62100000, 0xe2100000, 0x7fffffff,
+        0xffffffff, 0xc276a000, 0x4276a000,
+    ]
+    .iter()
+    .cloned()
+    .map(F32::from_bits)
+}
+
+pub(crate) fn f128s() -> impl Iterator<Item = F128> {
+    f64s().map(widen_f64)
+}
+
+pub(crate) fn widen_f32(value: F32) -> F64 {
+    F64::from_bits(u64::from(value.to_bits()) << 32)
+}
+
+pub(crate) fn widen_f64(value: F64) -> F128 {
+    F128::from_bits(F128::with_low_order_part(u128::from(value.to_bits()) << 64))
+}
+
+// 16^exponent as an f64, since `powi()` needs std
+fn pow16(exponent: i32) -> f64 {
+    f64::from_bits(((1023 + 4 * exponent) as u64) << 52)
+}
+
+pub(crate) fn f64s() -> impl Iterator<Item = F64> {
+    [
+        0x0000000000000001,
+        0x8000000000000001,
+        0x0000000000000002,
+        0x8000000000000002,
+        0x0000000000000003,
+        0x8000000000000003,
+        0x400ffffffffffffe,
+        0xc00ffffffffffffe,
+        0x400fffffffffffff,
+        0xc00fffffffffffff,
+        0x4010000000000000,
+        0xc010000000000000,
+        0x4010000000000001,
+        0xc010000000000001,
+        0x4010000000000002,
+        0xc010000000000002,
+        0x401ffffffffffffe,
+        0xc01ffffffffffffe,
+        0x401fffffffffffff,
+        0xc01fffffffffffff,
+        0x4020000000000000,
+        0xc020000000000000,
+        0x4020000000000001,
+        0xc020000000000001,
+        0x4020000000000002,
+        0xc020000000000002,
+        0x4020000000000003,
+        0xc020000000000003,
+        0x403ffffffffffffd,
+        0xc03ffffffffffffd,
+        0x403ffffffffffffe,
+        0xc03ffffffffffffe,
+        0x403fffffffffffff,
+        0xc03fffffffffffff,
+        0x4040000000000002,
+        0xc040000000000002,
+        0x4040000000000003,
+        0xc040000000000003,
+        0x4040000000000005,
+        0xc040000000000005,
+        0x4040000000000006,
+        0xc040000000000006,
+        0x407ffffffffffffa,
+        0xc07ffffffffffffa,
+        0x407ffffffffffffb,
+        0xc07ffffffffffffb,
+        0x407ffffffffffffd,
+        0xc07ffffffffffffd,
+        0x407ffffffffffffe,
+        0xc07ffffffffffffe,
+        0x4080000000000004,
+        0xc080000000000004,
+        0x4080000000000005,
+        0xc080000000000005,
+        0x408000000000000b,
+        0xc08000000000000b,
+        0x408000000000000c,
+        0xc08000000000000c,
+        0x40fffffffffffff4,
+        0xc0fffffffffffff4,
+        0x40fffffffffffff5,
+        0xc0fffffffffffff5,
+        0x40fffffffffffffb,
+        0xc0fffffffffffffb,
+        0x40fffffffffffffc,
+        0xc0fffffffffffffc,
+        0x4110000000000000,
+        0xc110000000000000,
+        0x4110000000000001,
+        0xc110000000000001,
+        0x4110000000000002,
+        0xc110000000000002,
+        0x411fffffffffffff,
+        0xc11fffffffffffff,
+        0x4120000000000000,
+        0xc120000000000000,
+        0x4120000000000001,
+        0xc120000000000001,
+        0x4120000000000002,
+        0xc120000000000002,
+        0x4800000000000001,
+        0xc800000000000001,
+        0x4800000000000002,
+        0xc800000000000002,
+        0x4800000000000004,
+        0xc800000000000004,
+        0x4800000000000008,
+        0xc800000000000008,
+        0x4800000000000010,
+        0xc800000000000010,
+        0x4800000000000020,
+        0xc800000000000020,
+        0x4800000000000040,
+        0xc800000000000040,
+        0x4800000000000080,
+        0xc800000000000080,
+        0x4800000000000100,
+        0xc800000000000100,
+        0x4800000000000200,
+        0xc800000000000200,
+        0x4800000000000400,
+        0xc800000000000400,
+        0x4800000000000800,
+        0xc800000000000800,
+        0x4800000000001000,
+        0xc800000000001000,
+        0x4800000000002000,
+        0xc800000000002000,
+        0x4800000000004000,
+        0xc800000000004000,
+        0x4800000000008000,
+        0xc800000000008000,
+        0x4800000000010000,
+        0xc800000000010000,
+        0x4800000000020000,
+        0xc800000000020000,
+        0x4800000000040000,
+        0xc800000000040000,
+        0x4800000000080000,
+        0xc800000000080000,
+        0x4800000000100000,
+        0xc800000000100000,
+        0x4800000000200000,
+        0xc800000000200000,
+        0x4800000000400000,
+        0xc800000000400000,
+        0x4800000000800000,
+        0xc800000000800000,
+        0x4800000001000000,
+        0xc800000001000000,
+        0x4800000002000000,
+        0xc800000002000000,
+        0x4800000004000000,
+        0xc800000004000000,
+        0x4800000008000000,
+        0xc800000008000000,
+        0x4800000010000000,
+        0xc800000010000000,
+        0x4800000020000000,
+        0xc800000020000000,
+        0x4800000040000000,
+        0xc800000040000000,
+        0x4800000080000000,
+        0xc800000080000000,
+        0x4800000100000000,
+        0xc800000100000000,
+        0x4800000200000000,
+        0xc800000200000000,
+        0x4800000400000000,
+        0xc800000400000000,
+        0x4800000800000000,
+        0xc800000800000000,
+        0x4800001000000000,
+        0xc800001000000000,
+        0x4800002000000000,
+        0xc800002000000000,
+        0x4800004000000000,
+        0xc800004000000000,
+        0x4800008000000000,
+        0xc800008000000000,
+        0x4800010000000000,
+        0xc800010000000000,
+        0x4800020000000000,
+        0xc800020000000000,
+        0x4800040000000000,
+        0xc800040000000000,
+        0x4800080000000000,
+        0xc800080000000000,
+        0x4800100000000000,
+        0xc800100000000000,
+        0x4800200000000000,
+        0xc800200000000000,
+        0x4800400000000000,
+        0xc800400000000000,
+        0x4800800000000000,
+        0xc800800000000000,
+        0x4801000000000000,
+        0xc801000000000000,
+        0x4802000000000000,
+        0xc802000000000000,
+        0x4804000000000000,
+        0xc804000000000000,
+        0x4808000000000000,
+        0xc808000000000000,
+        0x4810000000000000,
+        0xc810000000000000,
+        0x4820000000000000,
+        0xc820000000000000,
+        0x4840000000000000,
+        0xc840000000000000,
+        0x4880000000000000,
+        0xc880000000000000,
+        0x567faef3ff3dc282,
+        0xd67faef3ff3dc282,
+        0x7ffffffffffffff4,
+        0xfffffffffffffff4,
+        0x7ffffffffffffff5,
+        0xfffffffffffffff5,
+        0x7ffffffffffffffb,
+        0xfffffffffffffffb,
+        0x7ffffffffffffffc,
+        0xfffffffffffffffc,
+        0x7ffffffffffffffd,
+        0xfffffffffffffffd,
+        0x7ffffffffffffffe,
+        0xfffffffffffffffe,
+        0x7fffffffffffffff,
+        0xffffffffffffffff,
+        0x0000000000000000,
+        0x8000000000000000,
+        0x0000000100000000,
+        0x8000000100000000,
+        0x00ffffff00000000,
+        0x80ffffff00000000,
+        0x4110000000000000,
+        0xc110000000000000,
+        0x7fffffff00000000,
+        0xffffffff00000000,
+    ]
+    .iter()
+    .cloned()
+    .map(F64::from_bits)
+}
@@ -0,0 +1,134 @@
+#[cfg(not(feature = "std"))]
+use core::{cmp::Ordering, hash};
+#[cfg(feature = "std")]
+use std::{cmp::Ordering, hash};
+
+use crate::compare::Normalized;
+use crate::{F32, F64};
+
+impl F32 {
+    /// Return the ordering between `self` and `other`, defining a total order over every `F32`.
+    ///
+    /// Values are ordered numerically, as with `PartialOrd`. Numerically equal values with
+    /// different encodings, namely the various zeros and the unnormalized forms of a value, are
+    /// ordered negative signs first, and then by their encoding interpreted as an unsigned integer.
+    /// As a result, `total_cmp()` returns `Ordering::Equal` only for identical encodings.
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use ibmfloat::F32;
+    ///
+    /// let one = F32::from_bits(0x41100000);
+    /// let unnormalized_one = F32::from_bits(0x42010000);
+    /// let positive_zero = F32::from_bits(0x00000000);
+    /// let negative_zero = F32::from_bits(0x80000000);
+    ///
+    /// assert_eq!(positive_zero.total_cmp(&one), Ordering::Less);
+    /// assert_eq!(negative_zero.total_cmp(&positive_zero), Ordering::Less);
+    /// assert_eq!(one.total_cmp(&unnormalized_one), Ordering::Less);
+    /// assert_eq!(one.total_cmp(&one), Ordering::Equal);
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        Normalized::from(*self)
+            .cmp(&Normalized::from(*other))
+            .then_with(|| other.is_sign_negative().cmp(&self.is_sign_negative()))
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl F64 {
+    /// Return the ordering between `self` and `other`, defining a total order over every `F64`.
+    ///
+    /// Values are ordered numerically, as with `PartialOrd`. Numerically equal values with
+    /// different encodings, namely the various zeros and the unnormalized forms of a value, are
+    /// ordered negative signs first, and then by their encoding interpreted as an unsigned integer.
+    /// As a result, `total_cmp()` returns `Ordering::Equal` only for identical encodings.
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use ibmfloat::F64;
+    ///
+    /// let one = F64::from_bits(0x4110000000000000);
+    /// let unnormalized_one = F64::from_bits(0x4201000000000000);
+    /// let negative_zero = F64::from_bits(0x8000000000000000);
+    ///
+    /// assert_eq!(negative_zero.total_cmp(&one), Ordering::Less);
+    /// assert_eq!(unnormalized_one.total_cmp(&one), Ordering::Greater);
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        Normalized::from(*self)
+            .cmp(&Normalized::from(*other))
+            .then_with(|| other.is_sign_negative().cmp(&self.is_sign_negative()))
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+/// A wrapper which implements `Eq`, `Ord`, and `Hash` for an IBM float using its `total_cmp()`
+/// method.
+///
+/// IBM floats have no NaN, so every pair of values is comparable, but `F32` and `F64` only
+/// implement `PartialOrd` because distinct encodings can be numerically equal. `TotalOrder`
+/// orders values numerically, breaking ties as described by
+/// [`F32::total_cmp`](struct.F32.html#method.total_cmp), so slices can be sorted and binary
+/// searched directly. Since only identical encodings are equal, values hash by their encoding,
+/// allowing them to be used as `HashMap` or `HashSet` keys.
+///
+/// ```
+/// use ibmfloat::{F32, TotalOrder};
+///
+/// let mut values: Vec<_> = [0x41200000, 0xc1100000, 0x00000000, 0x41100000]
+///     .iter()
+///     .map(|&bits| TotalOrder(F32::from_bits(bits)))
+///     .collect();
+/// values.sort();
+///
+/// let sorted: Vec<u32> = values.iter().map(|v| v.0.to_bits()).collect();
+/// assert_eq!(sorted, [0xc1100000, 0x00000000, 0x41100000, 0x41200000]);
+///
+/// assert_eq!(values.binary_search(&TotalOrder(F32::from_bits(0x41100000))), Ok(2));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct TotalOrder<T>(pub T);
+
+macro_rules! total_order {
+    ($t:ty) => {
+        impl From<$t> for TotalOrder<$t> {
+            #[inline]
+            fn from(v: $t) -> Self {
+                Self(v)
+            }
+        }
+
+        impl PartialEq for TotalOrder<$t> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0.to_bits() == other.0.to_bits()
+            }
+        }
+
+        impl Eq for TotalOrder<$t> {}
+
+        impl hash::Hash for TotalOrder<$t> {
+            #[inline]
+            fn hash<H: hash::Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state)
+            }
+        }
+
+        impl PartialOrd for TotalOrder<$t> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for TotalOrder<$t> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+    };
+}
+total_order!(F32);
+total_order!(F64);
@@ -0,0 +1,143 @@
+//! Byte-aligned big-endian storage types, suitable for viewing IBM floats in place.
+//!
+//! Reinterpreting byte slices as slices of these types is left to `bytemuck`, so that this crate
+//! remains free of `unsafe` code.
+
+#[cfg(not(feature = "std"))]
+use core::mem;
+#[cfg(feature = "std")]
+use std::{fmt, mem};
+
+use crate::{F32, F64};
+
+/// A 32-bit IBM floating point number stored as big-endian bytes.
+///
+/// Unlike [`F32`](struct.F32.html), which wraps a native-endian `u32`, `F32Be` wraps a `[u8; 4]`
+/// and therefore has an alignment of 1. With the `bytemuck` feature, this allows slices of `F32Be`
+/// to be overlaid directly onto byte buffers, such as memory-mapped files, without copying.
+///
+/// ```
+/// use ibmfloat::{F32Be, F32};
+///
+/// let value = F32Be::from_be_bytes([0x42, 0x76, 0xa0, 0x00]);
+/// assert_eq!(f32::from(value.get()), 118.625);
+/// assert_eq!(F32Be::new(F32::from_bits(0xc1100000)).to_be_bytes(), [0xc1, 0x10, 0, 0]);
+/// ```
+///
+/// With the `bytemuck` feature:
+///
+/// ```
+/// # #[cfg(feature = "bytemuck")] {
+/// use ibmfloat::F32Be;
+///
+/// let buffer = [0x42, 0x76, 0xa0, 0x00, 0xc1, 0x10, 0x00, 0x00, 0xff];
+/// let (values, rest) = F32Be::slice_from_bytes(&buffer);
+///
+/// assert_eq!(values.len(), 2);
+/// assert_eq!(f32::from(values[0].get()), 118.625);
+/// assert_eq!(f32::from(values[1].get()), -1.0);
+/// assert_eq!(rest, &[0xff]);
+/// # }
+/// ```
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(transparent)]
+pub struct F32Be([u8; 4]);
+
+/// A 64-bit IBM floating point number stored as big-endian bytes.
+///
+/// Unlike [`F64`](struct.F64.html), which wraps a native-endian `u64`, `F64Be` wraps a `[u8; 8]`
+/// and therefore has an alignment of 1. With the `bytemuck` feature, this allows slices of `F64Be`
+/// to be overlaid directly onto byte buffers, such as memory-mapped files, without copying.
+///
+/// ```
+/// use ibmfloat::F64Be;
+///
+/// let value = F64Be::from_be_bytes([0x41, 0x10, 0, 0, 0, 0, 0, 0]);
+/// assert_eq!(f64::from(value.get()), 1.0);
+/// ```
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(transparent)]
+pub struct F64Be([u8; 8]);
+
+macro_rules! unaligned {
+    ($t:ident, $f:ident, $N:expr) => {
+        // Overlaying byte buffers relies on these.
+        const _: () = assert!(mem::size_of::<$t>() == $N && mem::align_of::<$t>() == 1);
+
+        impl $t {
+            /// Store an IBM float as big-endian bytes.
+            #[inline]
+            pub const fn new(value: $f) -> Self {
+                Self(value.to_be_bytes())
+            }
+
+            /// Load the stored IBM float.
+            #[inline]
+            pub const fn get(self) -> $f {
+                $f::from_be_bytes(self.0)
+            }
+
+            /// Wrap a big-endian byte array.
+            #[inline]
+            pub const fn from_be_bytes(bytes: [u8; $N]) -> Self {
+                Self(bytes)
+            }
+
+            /// Return the stored big-endian byte array.
+            #[inline]
+            pub const fn to_be_bytes(self) -> [u8; $N] {
+                self.0
+            }
+        }
+
+        #[cfg(feature = "bytemuck")]
+        impl $t {
+            /// View a byte slice as a slice of values, without copying.
+            ///
+            /// Any trailing bytes which do not form a complete value are returned separately.
+            pub fn slice_from_bytes(bytes: &[u8]) -> (&[Self], &[u8]) {
+                let (values, rest) = bytes.split_at(bytes.len() - bytes.len() % $N);
+                (bytemuck::cast_slice(values), rest)
+            }
+
+            /// View a mutable byte slice as a mutable slice of values, without copying.
+            ///
+            /// Any trailing bytes which do not form a complete value are returned separately.
+            pub fn slice_from_bytes_mut(bytes: &mut [u8]) -> (&mut [Self], &mut [u8]) {
+                let len = bytes.len() - bytes.len() % $N;
+                let (values, rest) = bytes.split_at_mut(len);
+                (bytemuck::cast_slice_mut(values), rest)
+            }
+
+            /// View a slice of values as the underlying bytes, without copying.
+            pub fn slice_as_bytes(values: &[Self]) -> &[u8] {
+                bytemuck::cast_slice(values)
+            }
+        }
+
+        impl From<$f> for $t {
+            #[inline]
+            fn from(v: $f) -> Self {
+                Self::new(v)
+            }
+        }
+
+        impl From<$t> for $f {
+            #[inline]
+            fn from(v: $t) -> Self {
+                v.get()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.get().fmt(f)
+            }
+        }
+    };
+}
+unaligned!(F32Be, F32, 4);
+unaligned!(F64Be, F64, 8);